@@ -0,0 +1,6 @@
+hello = hello
+green = green
+items = { $count ->
+    [one] {$count} item
+   *[other] {$count} items
+}
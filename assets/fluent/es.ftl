@@ -0,0 +1,6 @@
+hello = hola
+green = verde
+items = { $count ->
+    [one] {$count} objeto
+   *[other] {$count} objetos
+}
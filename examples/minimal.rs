@@ -12,7 +12,7 @@ fn main() {
         // initialize as default
         Translations::default()
             // select a CSV file and a default locale
-            .csv_file(&Path::new("assets/lang.csv"), &"en".into())
+            .csv_file(Path::new("assets/lang.csv"), &"en".into())
             // optionally switch the current locale
             .use_locale("es")
             // Strips mutability to easily finish inserting into the world.
@@ -0,0 +1,128 @@
+//! Readers for Android `res/values-*/strings.xml` resources.
+
+use std::path::{Path, PathBuf};
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+/// Collects the locales of every `values-*` directory in the resource directory, sorted by locale, along with the directory itself.
+/// Directories with qualifiers other than a language and region, such as `values-night` or `values-v21`, are skipped.
+pub(crate) fn scan_locales(res_dir: &Path) -> Option<Vec<(String, PathBuf)>> {
+    let entries = std::fs::read_dir(res_dir).ok()?;
+    let mut locales = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_dir())
+        .filter_map(|path| Some((dir_locale(path.file_name()?.to_str()?)?, path)))
+        .collect::<Vec<_>>();
+    locales.sort();
+    Some(locales)
+}
+
+/// Reads the locale of a resource directory name, such as `es` from `values-es`, `pt-BR` from `values-pt-rBR`, and `sr-Latn` from `values-b+sr+Latn`.
+fn dir_locale(name: &str) -> Option<String> {
+    let qualifiers = name.strip_prefix("values-")?;
+    if let Some(tag) = qualifiers.strip_prefix("b+") {
+        let segments = tag.split('+').collect::<Vec<_>>();
+        if !segments
+            .iter()
+            .all(|s| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric()))
+        {
+            return None;
+        }
+        return Some(segments.join("-"));
+    }
+    let mut segments = qualifiers.split('-');
+    let lang = segments.next()?;
+    if !(2..=3).contains(&lang.len()) || !lang.chars().all(|c| c.is_ascii_lowercase()) {
+        return None;
+    }
+    match (segments.next(), segments.next()) {
+        (None, _) => Some(lang.into()),
+        (Some(region), None) => {
+            let region = region.strip_prefix('r')?;
+            let valid = (region.len() == 2 && region.chars().all(|c| c.is_ascii_uppercase()))
+                || (region.len() == 3 && region.chars().all(|c| c.is_ascii_digit()));
+            valid.then(|| format!("{}-{}", lang, region))
+        }
+        _ => None,
+    }
+}
+
+/// Parses the `<string name="key">value</string>` entries of a `strings.xml` resource, resolving Android's quoting and escape rules.
+/// Markup inside a string, such as `<b>`, is dropped and only its text is kept. Other resources like `<plurals>` and `<string-array>` are skipped.
+pub(crate) fn parse_strings(contents: &str) -> Result<Vec<(String, String)>, quick_xml::Error> {
+    let mut reader = Reader::from_str(contents);
+    let mut mapping = Vec::new();
+    // the key and raw text of the string currently being read, along with the depth of markup inside it
+    let mut current: Option<(String, String)> = None;
+    let mut depth = 0;
+    loop {
+        match reader.read_event()? {
+            Event::Start(_) if current.is_some() => depth += 1,
+            Event::Start(start) if start.name().as_ref() == b"string" => {
+                if let Some(name) = start.try_get_attribute("name")? {
+                    current = Some((name.unescape_value()?.into_owned(), String::new()));
+                }
+            }
+            Event::Text(text) => {
+                if let Some((_, value)) = &mut current {
+                    value.push_str(&text.unescape()?);
+                }
+            }
+            Event::CData(data) => {
+                if let Some((_, value)) = &mut current {
+                    value.push_str(&String::from_utf8_lossy(&data.into_inner()));
+                }
+            }
+            Event::End(_) if depth > 0 => depth -= 1,
+            Event::End(end) if end.name().as_ref() == b"string" => {
+                if let Some((key, value)) = current.take() {
+                    mapping.push((key, unescape(&value)));
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(mapping)
+}
+
+/// Applies Android's string rules: runs of whitespace collapse to a single space outside of double quotes, the quotes themselves are removed,
+/// and backslash escapes such as `\'`, `\"`, `\n`, `\t`, and `\u0041` are resolved.
+fn unescape(raw: &str) -> String {
+    let mut output = String::with_capacity(raw.len());
+    let mut quoted = false;
+    let mut space = false;
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() && !quoted {
+            space = !output.is_empty();
+            continue;
+        }
+        if space {
+            output.push(' ');
+            space = false;
+        }
+        match c {
+            '"' => quoted = !quoted,
+            '\\' => match chars.next() {
+                Some('n') => output.push('\n'),
+                Some('t') => output.push('\t'),
+                Some('u') => {
+                    let hex = chars.by_ref().take(4).collect::<String>();
+                    match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                        Some(c) => output.push(c),
+                        None => {
+                            output.push_str("\\u");
+                            output.push_str(&hex);
+                        }
+                    }
+                }
+                Some(other) => output.push(other),
+                None => output.push('\\'),
+            },
+            c => output.push(c),
+        }
+    }
+    output
+}
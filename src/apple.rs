@@ -0,0 +1,123 @@
+//! Readers for Apple `*.lproj/Localizable.strings` files.
+
+use std::iter::Peekable;
+use std::path::{Path, PathBuf};
+use std::str::Chars;
+
+/// Collects the locales of every `*.lproj` directory in the given directory, sorted by locale, along with the directory itself.
+/// `Base.lproj` holds Interface Builder files rather than a language and is skipped. Underscores are read as region delimiters, so `pt_BR.lproj` is `pt-BR`.
+pub(crate) fn scan_locales(dir: &Path) -> Option<Vec<(String, PathBuf)>> {
+    let entries = std::fs::read_dir(dir).ok()?;
+    let mut locales = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_dir() && path.extension().is_some_and(|ext| ext == "lproj"))
+        .filter_map(|path| Some((path.file_stem()?.to_str()?.replace('_', "-"), path)))
+        .filter(|(name, _)| name != "Base")
+        .collect::<Vec<_>>();
+    locales.sort();
+    Some(locales)
+}
+
+/// Decodes a `.strings` file, which Xcode writes as either UTF-16 with a byte order mark or UTF-8.
+pub(crate) fn decode(bytes: &[u8]) -> String {
+    let utf16 = |le: bool| {
+        let units = bytes[2..]
+            .chunks_exact(2)
+            .map(|pair| match le {
+                true => u16::from_le_bytes([pair[0], pair[1]]),
+                false => u16::from_be_bytes([pair[0], pair[1]]),
+            })
+            .collect::<Vec<u16>>();
+        String::from_utf16_lossy(&units)
+    };
+    match bytes {
+        [0xFF, 0xFE, ..] => utf16(true),
+        [0xFE, 0xFF, ..] => utf16(false),
+        _ => String::from_utf8_lossy(bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes)).into(),
+    }
+}
+
+/// Parses the `"key" = "value";` entries of a `.strings` file, skipping `/* */` and `//` comments and resolving escape sequences.
+/// Keys and values may also be written without quotes when they are plain words. Malformed entries are skipped up to the next `;`.
+pub(crate) fn parse_strings(contents: &str) -> Vec<(String, String)> {
+    let mut mapping = Vec::new();
+    let mut chars = contents.chars().peekable();
+    loop {
+        skip_blank(&mut chars);
+        if chars.peek().is_none() {
+            break;
+        }
+        let entry = read_token(&mut chars).and_then(|key| {
+            skip_blank(&mut chars);
+            chars.next_if_eq(&'=')?;
+            skip_blank(&mut chars);
+            let value = read_token(&mut chars)?;
+            skip_blank(&mut chars);
+            chars.next_if_eq(&';')?;
+            Some((key, value))
+        });
+        match entry {
+            Some(entry) => mapping.push(entry),
+            None => {
+                // resume after the end of the malformed entry
+                chars.by_ref().find(|c| *c == ';');
+            }
+        }
+    }
+    mapping
+}
+
+/// Skips whitespace and comments.
+fn skip_blank(chars: &mut Peekable<Chars>) {
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let mut ahead = chars.clone();
+        match (ahead.next(), ahead.next()) {
+            (Some('/'), Some('*')) => {
+                chars.nth(1);
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            (Some('/'), Some('/')) => {
+                chars.by_ref().find(|c| *c == '\n');
+            }
+            _ => return,
+        }
+    }
+}
+
+/// Reads a quoted string with its escapes resolved, or a bare word.
+fn read_token(chars: &mut Peekable<Chars>) -> Option<String> {
+    if chars.next_if_eq(&'"').is_none() {
+        let mut word = String::new();
+        while let Some(c) = chars.next_if(|c| c.is_alphanumeric() || "_.-$:/".contains(*c)) {
+            word.push(c);
+        }
+        return (!word.is_empty()).then_some(word);
+    }
+    let mut output = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(output),
+            '\\' => match chars.next()? {
+                'n' => output.push('\n'),
+                't' => output.push('\t'),
+                'r' => output.push('\r'),
+                'u' | 'U' => {
+                    let hex = chars.by_ref().take(4).collect::<String>();
+                    let c = u32::from_str_radix(&hex, 16)
+                        .ok()
+                        .and_then(char::from_u32)?;
+                    output.push(c);
+                }
+                other => output.push(other),
+            },
+            c => output.push(c),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use bevy_asset::{
+    io::Reader, Asset, AssetEvent, AssetLoader, Assets, AsyncReadExt, Handle, LoadContext,
+};
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypePath;
+use bevy_utils::BoxedFuture;
+use tracing::warn;
+
+use crate::{TranslationError, Translations};
+
+/// A translation table loaded through the bevy `AssetServer`. The whole source is kept in memory so the table can switch locales without touching the disk.
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct TranslationAsset {
+    /// The loaded table. No locale is selected yet, call `use_locale` on a copy to pick one.
+    pub translations: Translations,
+}
+
+/// The `AssetLoader` for `.csv` and `.ods` translation tables.
+#[derive(Default)]
+pub struct TranslationLoader;
+
+impl AssetLoader for TranslationLoader {
+    type Asset = TranslationAsset;
+    type Settings = ();
+    type Error = TranslationError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<TranslationAsset, TranslationError>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let extension = load_context
+                .path()
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .unwrap_or_default()
+                .to_lowercase();
+
+            let translations = read_table(bytes, &extension)?;
+            Ok(TranslationAsset { translations })
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &[
+            #[cfg(feature = "csv")]
+            "csv",
+            #[cfg(feature = "ods")]
+            "ods",
+        ]
+    }
+}
+
+/// Reads a table held in memory without selecting a locale, so only the header row is parsed until the resource picks a locale.
+#[cfg(any(feature = "csv", feature = "ods"))]
+fn read_table(bytes: Vec<u8>, extension: &str) -> Result<Translations, TranslationError> {
+    let mut translations = Translations::default();
+    match extension {
+        #[cfg(feature = "csv")]
+        "csv" => {
+            let text = String::from_utf8(bytes)
+                .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+            translations.csv_raw(text, &String::new());
+        }
+        #[cfg(feature = "ods")]
+        "ods" => {
+            translations.ods_bytes(bytes, &String::new(), crate::OdsSheet::default());
+        }
+        _ => return Err(unsupported_extension(extension)),
+    }
+    if translations.available_locales.is_empty() {
+        return Err(TranslationError::EmptyHeader);
+    }
+    Ok(translations)
+}
+
+#[cfg(not(any(feature = "csv", feature = "ods")))]
+fn read_table(_bytes: Vec<u8>, extension: &str) -> Result<Translations, TranslationError> {
+    Err(unsupported_extension(extension))
+}
+
+fn unsupported_extension(extension: &str) -> TranslationError {
+    std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        format!("unsupported translation table extension {:?}", extension),
+    )
+    .into()
+}
+
+/// The handle of the translation table loaded by `TranslationPlugin::from_asset`.
+#[derive(Resource, Clone, Debug)]
+pub struct TranslationHandle(pub Handle<TranslationAsset>);
+
+/// Copies the translation table into the `Translations` resource once the `AssetServer` finishes loading it, and again whenever it is modified.
+/// Only the table is replaced. The current locale and everything configured on the resource, such as the fallback chain, overrides, and missing key policy, are kept.
+/// When the table does not have the current locale, its first locale is used instead and a warning is logged.
+pub fn apply_translation_asset(
+    mut events: EventReader<AssetEvent<TranslationAsset>>,
+    handle: Option<Res<TranslationHandle>>,
+    assets: Res<Assets<TranslationAsset>>,
+    mut translations: ResMut<Translations>,
+) {
+    let Some(handle) = handle else {
+        return;
+    };
+    let ready = events
+        .read()
+        .filter(|event| {
+            event.is_loaded_with_dependencies(&handle.0) || event.is_modified(&handle.0)
+        })
+        .count()
+        > 0;
+    if !ready {
+        return;
+    }
+    let Some(asset) = assets.get(&handle.0) else {
+        return;
+    };
+    let previous = translations.current_locale();
+    let mut locale = translations.current_locale_string();
+    let mut pending = translations.pending_change.take();
+    let loaded = &asset.translations;
+    translations.path = loaded.path.clone();
+    translations.available_locales = loaded.available_locales.clone();
+    translations.mappings = loaded.mappings.clone();
+    translations.locale = loaded.locale.clone();
+    // merged sources and columns kept by eager loading belong to the previous table
+    translations.merged.clear();
+    translations.columns.clear();
+    if translations.resolve_locale(&previous).is_none() {
+        let Some(first) = translations.available_locales.first().cloned() else {
+            return;
+        };
+        let fallback = translations.locale_string(&first);
+        warn!(
+            "Locale {:?} is not available in the loaded translation table, falling back to {:?}",
+            locale, fallback
+        );
+        locale = fallback;
+        pending = pending.or(Some(previous));
+    }
+    // reloading through the resource applies its own configuration to the new table
+    translations.use_locale(locale);
+    // unless it had to fall back, the locale itself did not change, the table was only filled in
+    translations.pending_change = pending;
+}
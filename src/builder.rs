@@ -0,0 +1,80 @@
+#[cfg(any(feature = "csv", feature = "ods"))]
+use std::path::PathBuf;
+
+use crate::{LocaleCode, TableFile, Translations};
+
+/// Configures `Translations` from a single source so it is parsed once with the final locale, rather than loading one locale and then switching.
+///
+/// ```no_run
+/// # use bevy_translation_table::Translations;
+/// # #[cfg(feature = "csv")]
+/// # {
+/// let translations = Translations::builder()
+///     .source_csv("assets/lang.csv")
+///     .locale("es")
+///     .build();
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TranslationsBuilder {
+    source: TableFile,
+    locale: String,
+    fallback_chain: Vec<LocaleCode>,
+}
+
+impl Default for TranslationsBuilder {
+    fn default() -> Self {
+        Self {
+            source: TableFile::None,
+            locale: "en".into(),
+            fallback_chain: Vec::new(),
+        }
+    }
+}
+
+impl TranslationsBuilder {
+    /// Loads the table from the given CSV file.
+    #[cfg(feature = "csv")]
+    pub fn source_csv(self, path: impl Into<PathBuf>) -> Self {
+        self.source(TableFile::Csv(Self::path_string(path)))
+    }
+
+    /// Loads the table from the first sheet of the given ODS file.
+    #[cfg(feature = "ods")]
+    pub fn source_ods(self, path: impl Into<PathBuf>) -> Self {
+        self.source(TableFile::Ods(Self::path_string(path)))
+    }
+
+    /// Loads the table from any supported source, such as `TableFile::Json` for a directory of JSON files.
+    pub fn source(mut self, source: TableFile) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Sets the locale that is loaded. Defaults to `en`.
+    pub fn locale(mut self, locale: impl ToString) -> Self {
+        self.locale = locale.to_string();
+        self
+    }
+
+    /// Sets the ordered locales to fall back to when the locale is missing a key, as in `Translations::set_fallback_chain`.
+    pub fn fallback_chain(mut self, locales: Vec<LocaleCode>) -> Self {
+        self.fallback_chain = locales;
+        self
+    }
+
+    /// Loads the source with the selected locale. Without a source the translations are left empty.
+    pub fn build(self) -> Translations {
+        let mut translations = Translations {
+            fallback_chain: self.fallback_chain,
+            ..Default::default()
+        };
+        translations.load_source(self.source, &self.locale);
+        translations
+    }
+
+    #[cfg(any(feature = "csv", feature = "ods"))]
+    fn path_string(path: impl Into<PathBuf>) -> String {
+        path.into().to_str().unwrap_or_default().into()
+    }
+}
@@ -0,0 +1,143 @@
+//! A small least-recently-used cache for the results of `tr_args`.
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    sync::{Mutex, MutexGuard},
+};
+
+/// Memoizes interpolated strings by key and arguments, kept behind a `Mutex` so lookups can fill it through `&self`.
+/// Every entry remembers the template it was made from, so a hit is only used while the key still translates to the same template.
+pub(crate) struct ArgsCache {
+    capacity: usize,
+    state: Mutex<CacheState>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    tick: u64,
+    entries: HashMap<u64, Entry>,
+}
+
+struct Entry {
+    key: String,
+    template: String,
+    args: Vec<(String, String)>,
+    result: String,
+    used: u64,
+}
+
+impl ArgsCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::default(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CacheState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Returns the cached result for the key and arguments if it was made from the same template.
+    pub(crate) fn get(
+        &self,
+        key: &str,
+        template: &str,
+        args: &HashMap<String, String>,
+    ) -> Option<String> {
+        let mut state = self.lock();
+        state.tick += 1;
+        let tick = state.tick;
+        let entry = state.entries.get_mut(&Self::hash(key, args))?;
+        let same_args = entry.args.len() == args.len()
+            && entry
+                .args
+                .iter()
+                .all(|(name, value)| args.get(name) == Some(value));
+        if entry.key != key || entry.template != template || !same_args {
+            return None;
+        }
+        entry.used = tick;
+        Some(entry.result.clone())
+    }
+
+    /// Stores a result, evicting the least recently used entry when the cache is full.
+    pub(crate) fn insert(
+        &self,
+        key: &str,
+        template: &str,
+        args: &HashMap<String, String>,
+        result: String,
+    ) {
+        let mut state = self.lock();
+        let hash = Self::hash(key, args);
+        if state.entries.len() >= self.capacity && !state.entries.contains_key(&hash) {
+            let oldest = state
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.used)
+                .map(|(hash, _)| *hash);
+            if let Some(oldest) = oldest {
+                state.entries.remove(&oldest);
+            }
+        }
+        let used = state.tick;
+        state.entries.insert(
+            hash,
+            Entry {
+                key: key.to_string(),
+                template: template.to_string(),
+                args: args
+                    .iter()
+                    .map(|(name, value)| (name.clone(), value.clone()))
+                    .collect(),
+                result,
+                used,
+            },
+        );
+    }
+
+    pub(crate) fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    /// Hashes the key together with the arguments, independent of the iteration order of the map.
+    fn hash(key: &str, args: &HashMap<String, String>) -> u64 {
+        let args = args
+            .iter()
+            .map(|arg| {
+                let mut hasher = DefaultHasher::new();
+                arg.hash(&mut hasher);
+                hasher.finish()
+            })
+            .fold(0, u64::wrapping_add);
+        let mut hasher = DefaultHasher::new();
+        (key, args).hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Clones start out empty, since cached strings are cheap to rebuild.
+impl Clone for ArgsCache {
+    fn clone(&self) -> Self {
+        Self::new(self.capacity)
+    }
+}
+
+impl PartialEq for ArgsCache {
+    fn eq(&self, other: &Self) -> bool {
+        self.capacity == other.capacity
+    }
+}
+
+impl std::fmt::Debug for ArgsCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArgsCache")
+            .field("capacity", &self.capacity)
+            .field("len", &self.lock().entries.len())
+            .finish()
+    }
+}
@@ -0,0 +1,106 @@
+use std::{path::Path, sync::Arc};
+
+use fluent_bundle::{concurrent::FluentBundle, FluentResource};
+use tracing::warn;
+use unic_langid::LanguageIdentifier;
+
+pub use fluent_bundle::FluentArgs;
+
+use crate::{TableFile, Translations};
+
+/// The Fluent bundle of the current locale. Shared behind an `Arc` so `Translations` stays cheap to clone.
+#[derive(Clone)]
+pub(crate) struct FluentData(Arc<FluentBundle<FluentResource>>);
+
+impl PartialEq for FluentData {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl std::fmt::Debug for FluentData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("FluentData").field(&self.0.locales).finish()
+    }
+}
+
+impl Translations {
+    /// Modifies the current Translations data to load from a directory of Fluent `.ftl` files and load a particular locale.
+    /// Each file is named after its locale (`dir/en.ftl`, `dir/es.ftl`, ...) and the available locales are collected from those file names.
+    /// Messages can then be formatted with `tr_fluent`, while `tr` returns each message formatted without any arguments.
+    pub fn fluent_file(&mut self, dir: &Path, locale: &String) -> &mut Self {
+        let Some(locales) = Self::scan_locale_dir(dir, "ftl") else {
+            return self;
+        };
+        self.path = TableFile::Fluent(dir.to_str().unwrap_or_default().into());
+
+        let file = dir.join(format!("{}.ftl", locale));
+        let Ok(contents) = std::fs::read_to_string(&file) else {
+            load_error!("Failed to load fluent file: {}", file.display());
+            return self;
+        };
+        let resource = match FluentResource::try_new(contents) {
+            Ok(resource) => resource,
+            Err((resource, errors)) => {
+                warn!(
+                    "Fluent file {} contains errors: {:?}",
+                    file.display(),
+                    errors
+                );
+                resource
+            }
+        };
+        let ids = resource
+            .entries()
+            .filter_map(|entry| match entry {
+                fluent_syntax::ast::Entry::Message(message) => Some(message.id.name.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<String>>();
+
+        let lang_id = locale.parse::<LanguageIdentifier>().unwrap_or_default();
+        let mut bundle = FluentBundle::new_concurrent(vec![lang_id]);
+        bundle.set_use_isolating(false);
+        if let Err(errors) = bundle.add_resource(resource) {
+            warn!(
+                "Failed to add fluent messages from {}: {:?}",
+                file.display(),
+                errors
+            );
+        }
+        let bundle = FluentData(Arc::new(bundle));
+        let mapping = ids
+            .into_iter()
+            .filter_map(|id| {
+                let value = Self::format_fluent(&bundle, &id, None)?;
+                Some((id, value))
+            })
+            .collect::<Vec<(String, String)>>();
+
+        self.locale = self.locale_code(locale);
+        self.data(locales.into_iter(), mapping.into_iter(), true);
+        self.fluent = Some(bundle);
+        self.load_fallbacks()
+    }
+
+    /// Formats a Fluent message of the current locale with the given arguments, resolving plurals and selectors.
+    /// When the data was not loaded from Fluent files, or the message does not exist, this falls back to `tr`.
+    pub fn tr_fluent(&self, key: impl AsRef<str>, args: &FluentArgs) -> String {
+        let key = key.as_ref();
+        self.fluent
+            .as_ref()
+            .and_then(|bundle| Self::format_fluent(bundle, key, Some(args)))
+            .unwrap_or_else(|| self.tr(key))
+    }
+
+    fn format_fluent(bundle: &FluentData, id: &str, args: Option<&FluentArgs>) -> Option<String> {
+        let pattern = bundle.0.get_message(id)?.value()?;
+        let mut errors = Vec::new();
+        let value = bundle.0.format_pattern(pattern, args, &mut errors);
+        // messages with variables are expected to report errors when formatted without arguments
+        if !errors.is_empty() && args.is_some() {
+            warn!("Errors formatting fluent message {}: {:?}", id, errors);
+        }
+        Some(value.into_owned())
+    }
+}
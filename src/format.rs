@@ -0,0 +1,100 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use fixed_decimal::{FixedDecimal, FloatPrecision};
+use icu_calendar::{DateTime, Gregorian};
+use icu_datetime::{options::length, TypedDateTimeFormatter};
+use icu_decimal::FixedDecimalFormatter;
+use icu_locid::Locale;
+
+use crate::Translations;
+
+impl Translations {
+    /// Formats a number with the grouping separator and decimal mark of the current locale, such as `1,234.5` for `en` and `1.234,5` for `de`.
+    /// Unknown locales use the root conventions. Non-finite numbers are formatted as is.
+    pub fn format_number(&self, n: f64) -> String {
+        let Ok(decimal) = FixedDecimal::try_from_f64(n, FloatPrecision::Floating) else {
+            return n.to_string();
+        };
+        match FixedDecimalFormatter::try_new(&self.icu_locale().into(), Default::default()) {
+            Ok(formatter) => formatter.format_to_string(&decimal),
+            Err(_) => decimal.to_string(),
+        }
+    }
+
+    /// Formats a point in time as a medium length date with a short time in the conventions of the current locale, such as `Jan 2, 2024, 3:04 PM` for `en`.
+    /// The time is shown in UTC.
+    pub fn format_datetime(&self, time: SystemTime) -> String {
+        let minutes = match time.duration_since(UNIX_EPOCH) {
+            Ok(since) => (since.as_secs() / 60) as i64,
+            Err(err) => -(err.duration().as_secs().div_ceil(60) as i64),
+        };
+        let minutes = minutes.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+        let datetime =
+            DateTime::from_minutes_since_local_unix_epoch(minutes).to_calendar(Gregorian);
+        let options = length::Bag::from_date_time_style(length::Date::Medium, length::Time::Short);
+        match TypedDateTimeFormatter::<Gregorian>::try_new(
+            &self.icu_locale().into(),
+            options.into(),
+        ) {
+            Ok(formatter) => formatter.format_to_string(&datetime),
+            Err(_) => Self::iso_datetime(&datetime),
+        }
+    }
+
+    /// Formats a date and time in the locale independent ISO 8601 style, such as `2024-01-02 15:04`, for when no formatter can be created.
+    fn iso_datetime(datetime: &DateTime<Gregorian>) -> String {
+        format!(
+            "{:04}-{:02}-{:02} {:02}:{:02}",
+            datetime.date.year().number,
+            datetime.date.month().ordinal,
+            datetime.date.day_of_month().0,
+            datetime.time.hour.number(),
+            datetime.time.minute.number()
+        )
+    }
+
+    fn icu_locale(&self) -> Locale {
+        Locale::try_from_bytes(self.locale.to_string().as_bytes())
+            .or_else(|_| Locale::try_from_bytes(self.locale.lang.as_bytes()))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn with_locale(locale: &str) -> Translations {
+        Translations {
+            locale: locale.into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn format_number_separators() {
+        assert_eq!(with_locale("en").format_number(1234567.5), "1,234,567.5");
+        assert_eq!(with_locale("de").format_number(1234567.5), "1.234.567,5");
+        assert_eq!(with_locale("en-US").format_number(-42.0), "-42");
+        assert_eq!(with_locale("xx").format_number(1234.5), "1,234.5");
+    }
+
+    #[test]
+    fn format_datetime_locale() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_704_207_840);
+        assert_eq!(
+            with_locale("en").format_datetime(time),
+            "Jan 2, 2024, 3:04\u{202f}PM"
+        );
+        assert_ne!(
+            with_locale("de").format_datetime(time),
+            with_locale("en").format_datetime(time)
+        );
+
+        let datetime = DateTime::from_minutes_since_local_unix_epoch(1_704_207_840 / 60)
+            .to_calendar(Gregorian);
+        assert_eq!(Translations::iso_datetime(&datetime), "2024-01-02 15:04");
+    }
+}
@@ -0,0 +1,104 @@
+//! A small parser for gettext `.po` catalogs.
+
+/// The entry currently being read from the catalog.
+#[derive(Default)]
+struct Entry {
+    fuzzy: bool,
+    msgid: Option<String>,
+    msgstr: Option<String>,
+}
+
+/// Which string the continuation lines of the catalog are appended to.
+enum Field {
+    Id,
+    Str,
+    Other,
+}
+
+/// Parses the `msgid`/`msgstr` pairs of a `.po` catalog. Multi-line strings are joined and escape sequences resolved.
+/// The header entry, fuzzy entries, obsolete entries, and entries without a translation are skipped. For plural entries only the first form (`msgstr[0]`) is kept.
+pub(crate) fn parse_po(contents: &str) -> Vec<(String, String)> {
+    let mut mapping = Vec::new();
+    let mut entry = Entry::default();
+    let mut field = Field::Other;
+    for line in contents.lines().map(str::trim) {
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(flags) = line.strip_prefix("#,") {
+            finish(&mut entry, &mut mapping);
+            entry.fuzzy = flags.split(',').any(|flag| flag.trim() == "fuzzy");
+            field = Field::Other;
+        } else if line.starts_with('#') {
+            continue;
+        } else if line.starts_with('"') {
+            let text = unquote(line);
+            match field {
+                Field::Id => entry.msgid.get_or_insert_with(String::new).push_str(&text),
+                Field::Str => entry.msgstr.get_or_insert_with(String::new).push_str(&text),
+                Field::Other => {}
+            }
+        } else if let Some((keyword, value)) = line.split_once(char::is_whitespace) {
+            let text = unquote(value.trim());
+            match keyword {
+                "msgctxt" => {
+                    if entry.msgid.is_some() {
+                        finish(&mut entry, &mut mapping);
+                    }
+                    field = Field::Other;
+                }
+                "msgid" => {
+                    if entry.msgid.is_some() {
+                        finish(&mut entry, &mut mapping);
+                    }
+                    entry.msgid = Some(text);
+                    field = Field::Id;
+                }
+                "msgstr" | "msgstr[0]" => {
+                    entry.msgstr = Some(text);
+                    field = Field::Str;
+                }
+                _ => field = Field::Other,
+            }
+        }
+    }
+    finish(&mut entry, &mut mapping);
+    mapping
+}
+
+/// Stores the entry if it is a complete translation, then resets it for the next entry.
+fn finish(entry: &mut Entry, mapping: &mut Vec<(String, String)>) {
+    let entry = std::mem::take(entry);
+    if entry.fuzzy {
+        return;
+    }
+    if let (Some(msgid), Some(msgstr)) = (entry.msgid, entry.msgstr) {
+        if !msgid.is_empty() && !msgstr.is_empty() {
+            mapping.push((msgid, msgstr));
+        }
+    }
+}
+
+/// Strips the surrounding quotes of a string literal and resolves its escape sequences.
+fn unquote(literal: &str) -> String {
+    let inner = literal
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(literal);
+    let mut output = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => output.push('\n'),
+            Some('t') => output.push('\t'),
+            Some('r') => output.push('\r'),
+            Some(other) => output.push(other),
+            None => output.push('\\'),
+        }
+    }
+    output
+}
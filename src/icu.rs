@@ -0,0 +1,444 @@
+//! A small evaluator for ICU MessageFormat patterns.
+
+use std::collections::HashMap;
+
+use intl_pluralrules::{PluralCategory, PluralRuleType, PluralRules};
+use unic_langid::LanguageIdentifier;
+
+use crate::Translations;
+
+/// An argument passed to `tr_icu`. Numbers select `plural` and `selectordinal` cases, text selects `select` cases.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IcuValue {
+    String(String),
+    Number(f64),
+}
+
+impl IcuValue {
+    fn as_number(&self) -> Option<f64> {
+        match self {
+            IcuValue::Number(n) => Some(*n),
+            IcuValue::String(s) => s.trim().parse().ok(),
+        }
+    }
+}
+
+impl std::fmt::Display for IcuValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IcuValue::String(s) => f.write_str(s),
+            IcuValue::Number(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+impl From<&str> for IcuValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.into())
+    }
+}
+
+impl From<String> for IcuValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<f64> for IcuValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<i64> for IcuValue {
+    fn from(value: i64) -> Self {
+        Self::Number(value as f64)
+    }
+}
+
+impl From<i32> for IcuValue {
+    fn from(value: i32) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<usize> for IcuValue {
+    fn from(value: usize) -> Self {
+        Self::Number(value as f64)
+    }
+}
+
+/// A piece of a parsed pattern.
+#[derive(Debug)]
+enum Part {
+    Text(String),
+    /// The `#` inside a plural case, replaced by the number being pluralized.
+    Hash,
+    Argument(String),
+    Plural {
+        name: String,
+        ordinal: bool,
+        offset: f64,
+        cases: Vec<(String, Vec<Part>)>,
+    },
+    Select {
+        name: String,
+        cases: Vec<(String, Vec<Part>)>,
+    },
+}
+
+impl Translations {
+    /// Translates the key and evaluates its value as an ICU MessageFormat pattern, such as `{count, plural, one {# item} other {# items}}`.
+    /// Supports simple `{name}` and `{name, number}` arguments, `plural` (including `offset:` and `=N` cases), `selectordinal`, and `select`, using the CLDR plural rules of the current language.
+    /// Arguments missing from `args` are kept as is, and a value that fails to parse is returned unevaluated.
+    pub fn tr_icu(&self, key: impl AsRef<str>, args: &HashMap<String, IcuValue>) -> String {
+        let pattern = self.tr(key);
+        let mut parser = Parser {
+            chars: pattern.chars().collect(),
+            pos: 0,
+        };
+        match parser.message(false) {
+            Ok(parts) if parser.pos == parser.chars.len() => {
+                let mut output = String::with_capacity(pattern.len());
+                self.evaluate(&parts, args, None, &mut output);
+                output
+            }
+            _ => pattern,
+        }
+    }
+
+    fn evaluate(
+        &self,
+        parts: &[Part],
+        args: &HashMap<String, IcuValue>,
+        hash: Option<f64>,
+        output: &mut String,
+    ) {
+        for part in parts {
+            match part {
+                Part::Text(text) => output.push_str(text),
+                Part::Hash => match hash {
+                    Some(n) => output.push_str(&n.to_string()),
+                    None => output.push('#'),
+                },
+                Part::Argument(name) => match args.get(name) {
+                    Some(value) => output.push_str(&value.to_string()),
+                    None => {
+                        output.push('{');
+                        output.push_str(name);
+                        output.push('}');
+                    }
+                },
+                Part::Plural {
+                    name,
+                    ordinal,
+                    offset,
+                    cases,
+                } => {
+                    let value = args.get(name).and_then(IcuValue::as_number).unwrap_or(0.0);
+                    let exact = format!("={}", value);
+                    let category = self.icu_plural_category(value - offset, *ordinal);
+                    let case = cases
+                        .iter()
+                        .find(|(selector, _)| *selector == exact)
+                        .or_else(|| cases.iter().find(|(selector, _)| selector == category))
+                        .or_else(|| cases.iter().find(|(selector, _)| selector == "other"));
+                    if let Some((_, parts)) = case {
+                        self.evaluate(parts, args, Some(value - offset), output);
+                    }
+                }
+                Part::Select { name, cases } => {
+                    let value = args.get(name).map(|value| value.to_string());
+                    let case = cases
+                        .iter()
+                        .find(|(selector, _)| Some(selector) == value.as_ref())
+                        .or_else(|| cases.iter().find(|(selector, _)| selector == "other"));
+                    if let Some((_, parts)) = case {
+                        self.evaluate(parts, args, hash, output);
+                    }
+                }
+            }
+        }
+    }
+
+    fn icu_plural_category(&self, n: f64, ordinal: bool) -> &'static str {
+        // rules registered with `set_plural_rule` select the cardinal category of whole numbers
+        #[cfg(feature = "plural")]
+        if !ordinal
+            && n.fract() == 0.
+            && self
+                .plural_rules
+                .contains_key(&self.locale.lang.to_lowercase())
+        {
+            return Self::plural_suffix(self.plural_category(n as i64));
+        }
+        let rule_type = if ordinal {
+            PluralRuleType::ORDINAL
+        } else {
+            PluralRuleType::CARDINAL
+        };
+        let category = self
+            .locale
+            .lang
+            .parse::<LanguageIdentifier>()
+            .ok()
+            .and_then(|lang| PluralRules::create(lang, rule_type).ok())
+            .and_then(|rules| rules.select(n.abs()).ok())
+            .unwrap_or(PluralCategory::OTHER);
+        match category {
+            PluralCategory::ZERO => "zero",
+            PluralCategory::ONE => "one",
+            PluralCategory::TWO => "two",
+            PluralCategory::FEW => "few",
+            PluralCategory::MANY => "many",
+            PluralCategory::OTHER => "other",
+        }
+    }
+}
+
+/// Parses a pattern into its parts. Every method fails on malformed syntax, so the raw pattern can be returned instead.
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    /// Parses text and arguments up to an unmatched `}` or the end of the pattern.
+    fn message(&mut self, in_plural: bool) -> Result<Vec<Part>, ()> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        while let Some(c) = self.peek() {
+            match c {
+                '}' => break,
+                '{' => {
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(self.argument()?);
+                }
+                '#' if in_plural => {
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(Part::Hash);
+                    self.pos += 1;
+                }
+                '\'' => self.apostrophe(&mut text, in_plural),
+                c => {
+                    text.push(c);
+                    self.pos += 1;
+                }
+            }
+        }
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+        Ok(parts)
+    }
+
+    /// Handles ICU quoting: `''` is a single apostrophe, and an apostrophe before syntax characters quotes everything up to the next apostrophe.
+    fn apostrophe(&mut self, text: &mut String, in_plural: bool) {
+        self.pos += 1;
+        match self.peek() {
+            Some('\'') => {
+                text.push('\'');
+                self.pos += 1;
+            }
+            Some('{' | '}') => self.quoted(text),
+            Some('#') if in_plural => self.quoted(text),
+            _ => text.push('\''),
+        }
+    }
+
+    fn quoted(&mut self, text: &mut String) {
+        while let Some(c) = self.peek() {
+            self.pos += 1;
+            if c != '\'' {
+                text.push(c);
+            } else if self.peek() == Some('\'') {
+                text.push('\'');
+                self.pos += 1;
+            } else {
+                return;
+            }
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn word(&mut self) -> String {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| !c.is_whitespace() && !matches!(c, ',' | '{' | '}'))
+        {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ()> {
+        self.skip_whitespace();
+        if self.peek() != Some(expected) {
+            return Err(());
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Parses an argument starting at its `{` through its closing `}`.
+    fn argument(&mut self) -> Result<Part, ()> {
+        self.expect('{')?;
+        let name = self.word();
+        if name.is_empty() {
+            return Err(());
+        }
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(Part::Argument(name));
+        }
+        self.expect(',')?;
+        let kind = self.word();
+        self.skip_whitespace();
+        let part = match kind.as_str() {
+            "plural" | "selectordinal" => {
+                self.expect(',')?;
+                self.skip_whitespace();
+                let mut offset = 0.0;
+                if self.chars[self.pos..].starts_with(&['o', 'f', 'f', 's', 'e', 't', ':']) {
+                    self.pos += 7;
+                    offset = self.word().parse().map_err(|_| ())?;
+                }
+                Part::Plural {
+                    name,
+                    ordinal: kind == "selectordinal",
+                    offset,
+                    cases: self.cases(true)?,
+                }
+            }
+            "select" => {
+                self.expect(',')?;
+                Part::Select {
+                    name,
+                    cases: self.cases(false)?,
+                }
+            }
+            // number, date, and other formatted arguments are inserted as is, ignoring their style
+            _ => {
+                while self.peek().is_some_and(|c| c != '}') {
+                    self.pos += 1;
+                }
+                Part::Argument(name)
+            }
+        };
+        self.expect('}')?;
+        Ok(part)
+    }
+
+    /// Parses `selector {message}` pairs up to the `}` closing the argument. An `other` case is required.
+    fn cases(&mut self, in_plural: bool) -> Result<Vec<(String, Vec<Part>)>, ()> {
+        let mut cases = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') || self.peek().is_none() {
+                break;
+            }
+            let selector = self.word();
+            if selector.is_empty() {
+                return Err(());
+            }
+            self.expect('{')?;
+            let message = self.message(in_plural)?;
+            self.expect('}')?;
+            cases.push((selector, message));
+        }
+        if !cases.iter().any(|(selector, _)| selector == "other") {
+            return Err(());
+        }
+        Ok(cases)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn icu_data(locale: &str) -> Translations {
+        let mut t = Translations::default();
+        t.data(
+            [locale].into_iter(),
+            [
+                ("items", "{count, plural, =0 {no items} one {# item} other {# items}}"),
+                ("place", "{n, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}"),
+                (
+                    "invite",
+                    "{host} invited {guests, plural, offset:1 =0 {nobody} =1 {{guest}} other {{guest} and # others}}",
+                ),
+                ("left", "{gender, select, feminine {She} masculine {He} other {They}} left"),
+                ("quoted", "It''s '{literal}' {name}"),
+                ("broken", "{count, plural, one {# item}"),
+            ]
+            .into_iter(),
+            true,
+        );
+        t.locale = locale.into();
+        t
+    }
+
+    fn args<const N: usize>(values: [(&str, IcuValue); N]) -> HashMap<String, IcuValue> {
+        values
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect()
+    }
+
+    #[test]
+    fn tr_icu_plural() {
+        let t = icu_data("en");
+        assert_eq!(t.tr_icu("items", &args([("count", 0.into())])), "no items");
+        assert_eq!(t.tr_icu("items", &args([("count", 1.into())])), "1 item");
+        assert_eq!(t.tr_icu("items", &args([("count", 5.into())])), "5 items");
+        assert_eq!(t.tr_icu("place", &args([("n", 2.into())])), "2nd");
+        assert_eq!(t.tr_icu("place", &args([("n", 13.into())])), "13th");
+        assert_eq!(
+            t.tr_icu(
+                "invite",
+                &args([
+                    ("host", "Ana".into()),
+                    ("guest", "Ben".into()),
+                    ("guests", 3.into())
+                ])
+            ),
+            "Ana invited Ben and 2 others"
+        );
+    }
+
+    #[test]
+    fn tr_icu_select_and_quotes() {
+        let t = icu_data("en");
+        assert_eq!(
+            t.tr_icu("left", &args([("gender", "feminine".into())])),
+            "She left"
+        );
+        assert_eq!(t.tr_icu("left", &HashMap::new()), "They left");
+        assert_eq!(
+            t.tr_icu("quoted", &args([("name", "Ana".into())])),
+            "It's {literal} Ana"
+        );
+        assert_eq!(
+            t.tr_icu("broken", &HashMap::new()),
+            "{count, plural, one {# item}"
+        );
+        assert_eq!(t.tr_icu("invalid", &HashMap::new()), "invalid");
+    }
+}
@@ -1,11 +1,91 @@
-use std::{collections::HashMap, path::Path};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
 
+#[cfg(feature = "reflect")]
+use bevy_ecs::reflect::ReflectResource;
 use bevy_ecs::system::Resource;
+use tracing::{debug, warn};
 
 #[cfg(feature = "ods")]
 use spreadsheet_ods::CellContent;
 
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// Reports a failure to load translation data. With the `strict` feature, debug builds panic instead so a renamed or broken file is noticed on the first run.
+// unused when only loading data from code, without any file format features
+#[allow(unused_macros)]
+macro_rules! load_error {
+    ($($arg:tt)*) => {
+        if cfg!(all(feature = "strict", debug_assertions)) {
+            panic!($($arg)*);
+        } else {
+            tracing::error!($($arg)*);
+        }
+    };
+}
+
+#[cfg(feature = "android")]
+mod android;
+
+#[cfg(feature = "apple")]
+mod apple;
+
+#[cfg(feature = "bevy_asset")]
+mod asset;
+#[cfg(feature = "bevy_asset")]
+pub use asset::*;
+
+mod builder;
+pub use builder::TranslationsBuilder;
+
+mod cache;
+
+#[cfg(feature = "bevy_app")]
+mod plugin;
+#[cfg(feature = "bevy_app")]
+pub use plugin::*;
+
+#[cfg(feature = "fluent")]
+mod fluent;
+#[cfg(feature = "fluent")]
+pub use fluent::FluentArgs;
+
+#[cfg(feature = "format")]
+mod format;
+
+#[cfg(feature = "gettext")]
+mod gettext;
+
+#[cfg(feature = "icu")]
+mod icu;
+#[cfg(feature = "icu")]
+pub use icu::IcuValue;
+
+#[cfg(feature = "plural")]
+mod plural;
+#[cfg(feature = "plural")]
+pub use plural::PluralCategory;
+
+#[cfg(feature = "bevy_text")]
+mod text;
+#[cfg(feature = "bevy_text")]
+pub use text::{update_localized_text, LocalizedText, StyleMap};
+
+#[cfg(feature = "async")]
+mod task;
+#[cfg(feature = "async")]
+pub use task::*;
+
+#[cfg(feature = "watch")]
+mod watch;
+#[cfg(feature = "watch")]
+pub use watch::*;
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
 /// An enum describing the currently supported types of table storage, As well as some reference data for loading different columns
 pub enum TableFile {
     #[cfg(feature = "csv")]
@@ -14,70 +94,583 @@ pub enum TableFile {
     CsvRaw(String),
     #[cfg(feature = "ods")]
     Ods(String),
+    #[cfg(feature = "ods")]
+    OdsRaw(Vec<u8>),
+    #[cfg(feature = "xlsx")]
+    Xlsx(String),
+    #[cfg(feature = "json")]
+    Json(String),
+    #[cfg(feature = "toml")]
+    Toml(String),
+    #[cfg(feature = "yaml")]
+    Yaml(String),
+    #[cfg(feature = "gettext")]
+    Po(String),
+    #[cfg(feature = "fluent")]
+    Fluent(String),
+    #[cfg(feature = "android")]
+    Android(String),
+    #[cfg(feature = "apple")]
+    Apple(String),
+    /// Every locale with its key-value pairs, as loaded by `data_multi` and `memory`.
+    Memory(Vec<(String, Vec<(String, String)>)>),
     None,
 }
+
+/// Tables held in memory are summarized by their size, so debug output of large embedded tables stays readable.
+impl std::fmt::Debug for TableFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            #[cfg(feature = "csv")]
+            TableFile::CsvRaw(data) => write!(f, "CsvRaw(<{} bytes>)", data.len()),
+            #[cfg(feature = "ods")]
+            TableFile::OdsRaw(bytes) => write!(f, "OdsRaw(<{} bytes>)", bytes.len()),
+            TableFile::Memory(table) => write!(f, "Memory(<{} locales>)", table.len()),
+            TableFile::None => f.write_str("None"),
+            #[cfg(feature = "csv")]
+            TableFile::Csv(path) => f.debug_tuple("Csv").field(path).finish(),
+            #[cfg(feature = "ods")]
+            TableFile::Ods(path) => f.debug_tuple("Ods").field(path).finish(),
+            #[cfg(feature = "xlsx")]
+            TableFile::Xlsx(path) => f.debug_tuple("Xlsx").field(path).finish(),
+            #[cfg(feature = "json")]
+            TableFile::Json(path) => f.debug_tuple("Json").field(path).finish(),
+            #[cfg(feature = "toml")]
+            TableFile::Toml(path) => f.debug_tuple("Toml").field(path).finish(),
+            #[cfg(feature = "yaml")]
+            TableFile::Yaml(path) => f.debug_tuple("Yaml").field(path).finish(),
+            #[cfg(feature = "gettext")]
+            TableFile::Po(path) => f.debug_tuple("Po").field(path).finish(),
+            #[cfg(feature = "fluent")]
+            TableFile::Fluent(path) => f.debug_tuple("Fluent").field(path).finish(),
+            #[cfg(feature = "android")]
+            TableFile::Android(path) => f.debug_tuple("Android").field(path).finish(),
+            #[cfg(feature = "apple")]
+            TableFile::Apple(path) => f.debug_tuple("Apple").field(path).finish(),
+        }
+    }
+}
+
+impl TableFile {
+    /// The file or directory the table is read from, or `None` for sources held in memory.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            #[cfg(feature = "csv")]
+            TableFile::Csv(path) => Some(Path::new(path)),
+            #[cfg(feature = "ods")]
+            TableFile::Ods(path) => Some(Path::new(path)),
+            #[cfg(feature = "xlsx")]
+            TableFile::Xlsx(path) => Some(Path::new(path)),
+            #[cfg(feature = "json")]
+            TableFile::Json(path) => Some(Path::new(path)),
+            #[cfg(feature = "toml")]
+            TableFile::Toml(path) => Some(Path::new(path)),
+            #[cfg(feature = "yaml")]
+            TableFile::Yaml(path) => Some(Path::new(path)),
+            #[cfg(feature = "gettext")]
+            TableFile::Po(path) => Some(Path::new(path)),
+            #[cfg(feature = "fluent")]
+            TableFile::Fluent(path) => Some(Path::new(path)),
+            #[cfg(feature = "android")]
+            TableFile::Android(path) => Some(Path::new(path)),
+            #[cfg(feature = "apple")]
+            TableFile::Apple(path) => Some(Path::new(path)),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "csv")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+/// Options controlling how CSV data is parsed. The defaults match the behavior of `csv_file`, which uses backslash escapes rather than the RFC-4180 doubled quotes that Excel exports.
+pub struct CsvOptions {
+    /// The field delimiter, such as `b';'` for many European exports.
+    pub delimiter: u8,
+    /// Whether the first row holds the locale codes.
+    pub has_headers: bool,
+    /// Whether two consecutive quotes (`""`) inside a quoted field are read as a single quote.
+    pub double_quote: bool,
+    /// The escape character used inside quoted fields, if any.
+    pub escape: Option<u8>,
+    /// Whether whitespace around fields is trimmed while parsing.
+    pub trim: bool,
+    /// Rows starting with this byte, such as `Some(b'#')`, are skipped as comments. Disabled by default, so keys starting with `#` are still read.
+    pub comment: Option<u8>,
+    /// Whether rows too short to reach the locale column are reported with a warning and collected into `Translations::short_rows`, such as after a trailing comma was deleted.
+    pub report_short_rows: bool,
+    /// The locales of the columns after the key column, in order. When not empty they are used instead of reading a header row, see `CsvOptions::headerless`.
+    pub locales: Vec<String>,
+}
+
+#[cfg(feature = "csv")]
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            has_headers: true,
+            double_quote: false,
+            escape: Some(b'\\'),
+            trim: true,
+            comment: None,
+            report_short_rows: false,
+            locales: Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl CsvOptions {
+    /// Options for RFC-4180 style files as exported by Excel, where quotes inside quoted fields are doubled.
+    pub fn excel() -> Self {
+        Self {
+            double_quote: true,
+            escape: None,
+            ..Default::default()
+        }
+    }
+
+    /// Options for tables without a header row, such as machine-generated files, where the first row already holds data.
+    /// The columns after the key column hold the given locales in order.
+    pub fn headerless<S: ToString>(locales: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            has_headers: false,
+            locales: locales.into_iter().map(|l| l.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn reader_builder(&self) -> csv::ReaderBuilder {
+        let mut builder = csv::ReaderBuilder::new();
+        builder
+            .delimiter(self.delimiter)
+            .has_headers(self.has_headers)
+            .double_quote(self.double_quote)
+            .escape(self.escape)
+            .comment(self.comment)
+            .flexible(true)
+            .trim(if self.trim {
+                csv::Trim::All
+            } else {
+                csv::Trim::None
+            });
+        builder
+    }
+}
+
+#[cfg(feature = "ods")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+/// Selects which sheet(s) of an ODS workbook are loaded.
+pub enum OdsSheet {
+    /// The sheet at the given index, starting at 0.
+    Index(usize),
+    /// The sheet with the given name.
+    Name(String),
+    /// Every sheet, merged together. Each key is prefixed with its sheet name and a `.`, such as `dialogue.greeting`.
+    AllPrefixed,
+}
+
+#[cfg(feature = "ods")]
+impl Default for OdsSheet {
+    fn default() -> Self {
+        Self::Index(0)
+    }
+}
+
+#[cfg(feature = "ods")]
+impl From<usize> for OdsSheet {
+    fn from(value: usize) -> Self {
+        Self::Index(value)
+    }
+}
+
+#[cfg(feature = "ods")]
+impl From<&str> for OdsSheet {
+    fn from(value: &str) -> Self {
+        Self::Name(value.into())
+    }
+}
+
+#[cfg(feature = "ods")]
+impl From<String> for OdsSheet {
+    fn from(value: String) -> Self {
+        Self::Name(value)
+    }
+}
+
+#[cfg(feature = "ods")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+/// Selects how the cells of an ODS sheet are turned into text.
+pub enum OdsCellText {
+    /// Typed cells are formatted from their value, such as `50%` for a percentage or `$5` for a currency amount.
+    #[default]
+    Typed,
+    /// Cells are read as plain text. Text cells are used as is, and since the ODS reader does not keep the displayed string of typed cells,
+    /// numbers, percentages, and currency amounts are written from their stored value without any percent sign or currency symbol.
+    Raw,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+/// Selects what a lookup returns when the key is present but its value is empty.
+pub enum EmptyCellPolicy {
+    /// The empty string is returned as is.
+    #[default]
+    ReturnEmpty,
+    /// The empty value counts as missing, so `tr` echoes the key back and `tr_opt` returns `None`.
+    EchoKey,
+    /// The empty value counts as missing and the fallback chain is tried, echoing the key back if no fallback has a value either.
+    FallbackChain,
+}
+
+#[derive(Debug)]
+/// The errors that can occur while loading translation data.
+pub enum TranslationError {
+    /// The requested source file could not be found.
+    FileNotFound(PathBuf),
+    /// The header row of the table was missing or contained no locales.
+    EmptyHeader,
+    /// The requested locale is not one of the columns in the table.
+    LocaleNotFound(String),
+    /// The CSV data could not be parsed.
+    #[cfg(feature = "csv")]
+    ParseError(csv::Error),
+    /// The source data could not be read.
+    Io(std::io::Error),
+    /// The text is not a valid locale code.
+    InvalidLocale(String),
+}
+
+impl std::fmt::Display for TranslationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TranslationError::FileNotFound(path) => {
+                write!(f, "failed to locate file: {}", path.display())
+            }
+            TranslationError::EmptyHeader => write!(f, "collected empty locale list"),
+            TranslationError::LocaleNotFound(locale) => {
+                write!(f, "locale {:?} not found in table", locale)
+            }
+            #[cfg(feature = "csv")]
+            TranslationError::ParseError(err) => write!(f, "failed to parse csv data: {}", err),
+            TranslationError::Io(err) => write!(f, "failed to read translation data: {}", err),
+            TranslationError::InvalidLocale(code) => write!(f, "invalid locale code {:?}", code),
+        }
+    }
+}
+
+impl std::error::Error for TranslationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "csv")]
+            TranslationError::ParseError(err) => Some(err),
+            TranslationError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<csv::Error> for TranslationError {
+    fn from(value: csv::Error) -> Self {
+        TranslationError::ParseError(value)
+    }
+}
+
+impl From<std::io::Error> for TranslationError {
+    fn from(value: std::io::Error) -> Self {
+        TranslationError::Io(value)
+    }
+}
+
 #[derive(Clone, Debug, Default)]
-/// A helper struct for storing the two segments commonly used to denote a locale and region.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "String", from = "String")
+)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+/// A helper struct for storing the segments commonly used to denote a locale: a language, an optional script (as in `zh-Hans`), and a region.
 pub struct LocaleCode {
     lang: String,
+    script: String,
     region: String,
 }
 
 impl PartialEq for LocaleCode {
     fn eq(&self, other: &Self) -> bool {
         self.lang.to_lowercase() == other.lang.to_lowercase()
+            && self.script.to_lowercase() == other.script.to_lowercase()
             && self.region.to_lowercase() == other.region.to_lowercase()
     }
 }
 
+impl Eq for LocaleCode {}
+
+impl std::hash::Hash for LocaleCode {
+    // must agree with the case-insensitive `PartialEq`, so the segments are hashed in lowercase
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.lang.to_lowercase().hash(state);
+        self.script.to_lowercase().hash(state);
+        self.region.to_lowercase().hash(state);
+    }
+}
+
 impl LocaleCode {
-    // TODO: make this overrideable.
-    /// The delimiter expected and produced when combining language and region codes in a LocalCode
+    /// The default delimiter expected and produced when combining language and region codes in a LocalCode. `Translations::with_region_delimiter` can override this per instance.
     pub const REGION_DELIMITER: &'static str = "-";
+
+    /// Parses a locale code that separates its segments with the given delimiter, such as `en_US` with `_`.
+    /// Following BCP-47, a four letter segment directly after the language is treated as the script, as in `zh-Hans-CN`.
+    pub fn parse_with_delimiter(code: impl ToString, delimiter: &str) -> Self {
+        let code = code.to_string();
+        let Some((lang, rest)) = code.split_once(delimiter) else {
+            return LocaleCode {
+                lang: code.trim().into(),
+                ..Default::default()
+            };
+        };
+        let (script, region) = match rest.split_once(delimiter) {
+            Some((script, region)) if Self::is_script(script.trim()) => (script, region),
+            None if Self::is_script(rest.trim()) => (rest, ""),
+            _ => ("", rest),
+        };
+        LocaleCode {
+            lang: lang.trim().into(),
+            script: script.trim().into(),
+            region: region.trim().into(),
+        }
+    }
+
+    /// Whether both codes share a language, ignoring case, script, and region. `en-GB` and `en-US` share a language while `en` and `es` do not.
+    pub fn same_language(&self, other: &LocaleCode) -> bool {
+        self.lang.to_lowercase() == other.lang.to_lowercase()
+    }
+
+    fn is_script(segment: &str) -> bool {
+        segment.len() == 4 && segment.chars().all(|c| c.is_ascii_alphabetic())
+    }
+
+    /// Formats the locale code, separating the language, script, and region with the given delimiter.
+    pub fn to_string_with_delimiter(&self, delimiter: &str) -> String {
+        [&self.lang, &self.script, &self.region]
+            .into_iter()
+            .filter(|segment| !segment.is_empty())
+            .cloned()
+            .collect::<Vec<String>>()
+            .join(delimiter)
+    }
 }
 
 impl From<LocaleCode> for String {
     fn from(value: LocaleCode) -> Self {
-        if value.region.is_empty() {
-            value.lang.clone()
-        } else {
-            format!(
-                "{}{}{}",
-                value.lang,
-                LocaleCode::REGION_DELIMITER,
-                value.region
-            )
-        }
+        value.to_string_with_delimiter(LocaleCode::REGION_DELIMITER)
     }
 }
 
-impl<T> From<T> for LocaleCode
-where
-    T: ToString,
-{
-    fn from(value: T) -> Self {
-        let code = value.to_string();
-        if let Some((lang, region)) = code.split_once(Self::REGION_DELIMITER) {
-            return LocaleCode {
-                lang: lang.trim().into(),
-                region: region.trim().into(),
-            };
-        } else {
-            return LocaleCode {
-                lang: code.trim().into(),
-                region: "".into(),
-            };
+impl std::fmt::Display for LocaleCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_string_with_delimiter(Self::REGION_DELIMITER))
+    }
+}
+
+impl std::str::FromStr for LocaleCode {
+    type Err = TranslationError;
+
+    /// Parses a locale code like `From<&str>`, but fails when the language is empty or any segment contains characters other than ASCII letters and digits.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = Self::from(s);
+        let valid_segment = |segment: &str| segment.chars().all(|c| c.is_ascii_alphanumeric());
+        if code.lang.is_empty()
+            || !code.lang.chars().all(|c| c.is_ascii_alphabetic())
+            || !valid_segment(&code.script)
+            || !valid_segment(&code.region)
+        {
+            return Err(TranslationError::InvalidLocale(s.to_string()));
         }
+        Ok(code)
+    }
+}
+
+/// Parses any displayable value by reference, such as `&str`, `&String`, or `&Cow<str>`.
+/// The owned `String`, `Cow<str>`, and `Box<str>` convert by value as well. Values of other `ToString` types that used to be converted by value can be converted by reference instead, since a blanket conversion by value would conflict with `From<LocaleCode>`.
+impl<T: std::fmt::Display + ?Sized> From<&T> for LocaleCode {
+    fn from(value: &T) -> Self {
+        Self::parse_with_delimiter(value, Self::REGION_DELIMITER)
+    }
+}
+
+impl From<String> for LocaleCode {
+    fn from(value: String) -> Self {
+        Self::parse_with_delimiter(value, Self::REGION_DELIMITER)
+    }
+}
+
+impl From<std::borrow::Cow<'_, str>> for LocaleCode {
+    fn from(value: std::borrow::Cow<'_, str>) -> Self {
+        Self::parse_with_delimiter(value, Self::REGION_DELIMITER)
+    }
+}
+
+impl From<Box<str>> for LocaleCode {
+    fn from(value: Box<str>) -> Self {
+        Self::parse_with_delimiter(value, Self::REGION_DELIMITER)
+    }
+}
+
+/// Describes how `use_system_locale_outcome` or `use_preferred_locale` chose a locale.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SystemLocaleOutcome {
+    /// One of the preferred locales is available as is.
+    Detected(LocaleCode),
+    /// None of the preferred locales are available, but one of their languages is, such as `en` for `en-GB`.
+    FellBackToLang(LocaleCode),
+    /// Nothing matched the preferred locales, so the first available locale was used. The key column of a table is never picked.
+    FellBackToFirst(LocaleCode),
+    /// No locales are available to select.
+    NoneAvailable,
+}
+
+/// The `(key, locale)` pairs that `tr` failed to find, kept behind a `Mutex` so lookups can record them through `&self`.
+#[derive(Debug, Default)]
+struct MissingKeys(Mutex<HashSet<(String, String)>>);
+
+impl MissingKeys {
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashSet<(String, String)>> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Clone for MissingKeys {
+    fn clone(&self) -> Self {
+        Self(Mutex::new(self.lock().clone()))
+    }
+}
+
+impl PartialEq for MissingKeys {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other) || *self.lock() == *other.lock()
+    }
+}
+
+type MissingFn = dyn Fn(&str, &LocaleCode) + Send + Sync;
+
+/// The callback registered with `on_missing`, shared between clones of the resource.
+#[derive(Clone)]
+struct MissingCallback(Arc<MissingFn>);
+
+impl std::fmt::Debug for MissingCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MissingCallback")
+    }
+}
+
+impl PartialEq for MissingCallback {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
     }
 }
 
-#[derive(Clone, PartialEq, Debug, Resource)]
+#[derive(Clone, PartialEq, Resource)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "reflect", reflect(Resource))]
 /// The main Resource type that stores translation data.
+/// With the `serde` feature the loaded state can be saved and restored without reading the source again. Recorded missing keys and Fluent bundles are not serialized.
+/// Inside a bevy App, switch locales through `ResMut<Translations>` or the `SetLocale` event of the `TranslationPlugin` so `Changed<Translations>` and `Res::is_changed` report the switch,
+/// which lets systems redo expensive work such as text layout only when the language changed.
 pub struct Translations {
     locale: LocaleCode,
     path: TableFile,
     available_locales: Vec<LocaleCode>,
     mappings: HashMap<String, String>,
+    fallback_chain: Vec<LocaleCode>,
+    fallback_mappings: Vec<(LocaleCode, HashMap<String, String>)>,
+    pending_change: Option<LocaleCode>,
+    merged: Vec<(TableFile, String)>,
+    eager: bool,
+    columns: HashMap<LocaleCode, HashMap<String, String>>,
+    key_prefix: String,
+    key_column: usize,
+    preserve_whitespace: bool,
+    empty_cells: EmptyCellPolicy,
+    duplicate_keys: Vec<(String, String)>,
+    overrides: HashMap<LocaleCode, HashMap<String, String>>,
+    keep_overrides: bool,
+    identity: bool,
+    context_separator: String,
+    comment_prefix: String,
+    rtl_languages: Vec<String>,
+    fonts: HashMap<LocaleCode, PathBuf>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    missing_keys: Option<MissingKeys>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    on_missing: Option<MissingCallback>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    args_cache: Option<cache::ArgsCache>,
+    region_delimiter: String,
+    case_insensitive_keys: bool,
+    #[cfg(feature = "csv")]
+    csv_options: CsvOptions,
+    #[cfg(feature = "csv")]
+    short_rows: Vec<u64>,
+    #[cfg(feature = "ods")]
+    ods_sheet: OdsSheet,
+    #[cfg(feature = "ods")]
+    ods_cell_text: OdsCellText,
+    #[cfg(feature = "ods")]
+    ods_header_row: usize,
+    #[cfg(feature = "ods")]
+    ods_data_row: Option<usize>,
+    #[cfg(feature = "plural")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    plural_rules: HashMap<String, plural::PluralRule>,
+    #[cfg(feature = "fluent")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    fluent: Option<fluent::FluentData>,
+}
+
+/// Summarizes the loaded data by its size instead of listing every translation, so `dbg!` on a large table stays readable.
+impl std::fmt::Debug for Translations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fallbacks = self
+            .fallback_mappings
+            .iter()
+            .map(|(code, mapping)| (code, mapping.len()))
+            .collect::<Vec<_>>();
+        f.debug_struct("Translations")
+            .field("locale", &self.locale)
+            .field("path", &self.path)
+            .field("available_locales", &self.available_locales)
+            .field(
+                "mappings",
+                &format_args!("<{} entries>", self.mappings.len()),
+            )
+            .field("fallback_chain", &self.fallback_chain)
+            .field("fallback_mappings", &fallbacks)
+            .field("pending_change", &self.pending_change)
+            .field("merged", &self.merged)
+            .field("eager", &self.eager)
+            .field("columns", &format_args!("<{} locales>", self.columns.len()))
+            .field("key_prefix", &self.key_prefix)
+            .field("duplicate_keys", &self.duplicate_keys.len())
+            .field("on_missing", &self.on_missing)
+            .field("args_cache", &self.args_cache)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for Translations {
@@ -87,349 +680,3987 @@ impl Default for Translations {
             path: TableFile::None,
             available_locales: Vec::new(),
             mappings: HashMap::new(),
+            fallback_chain: Vec::new(),
+            fallback_mappings: Vec::new(),
+            pending_change: None,
+            merged: Vec::new(),
+            eager: false,
+            columns: HashMap::new(),
+            key_prefix: String::new(),
+            key_column: 0,
+            preserve_whitespace: false,
+            empty_cells: EmptyCellPolicy::default(),
+            duplicate_keys: Vec::new(),
+            overrides: HashMap::new(),
+            keep_overrides: true,
+            identity: false,
+            context_separator: "@".into(),
+            comment_prefix: "#".into(),
+            rtl_languages: Self::RTL_LANGUAGES
+                .iter()
+                .map(|lang| lang.to_string())
+                .collect(),
+            fonts: HashMap::new(),
+            missing_keys: None,
+            on_missing: None,
+            args_cache: None,
+            region_delimiter: LocaleCode::REGION_DELIMITER.into(),
+            case_insensitive_keys: false,
+            #[cfg(feature = "csv")]
+            csv_options: CsvOptions::default(),
+            #[cfg(feature = "csv")]
+            short_rows: Vec::new(),
+            #[cfg(feature = "ods")]
+            ods_sheet: OdsSheet::default(),
+            #[cfg(feature = "ods")]
+            ods_cell_text: OdsCellText::default(),
+            #[cfg(feature = "ods")]
+            ods_header_row: 0,
+            #[cfg(feature = "ods")]
+            ods_data_row: None,
+            #[cfg(feature = "plural")]
+            plural_rules: HashMap::new(),
+            #[cfg(feature = "fluent")]
+            fluent: None,
         }
     }
 }
 
 impl Translations {
+    /// The languages written right-to-left by default, used by `is_rtl`.
+    pub const RTL_LANGUAGES: &'static [&'static str] = &[
+        "ar", "arc", "ckb", "dv", "fa", "he", "iw", "ks", "nqo", "ps", "sd", "syr", "ug", "ur",
+        "yi",
+    ];
+
+    /// How many levels of `{$key}` references `tr` follows before leaving a reference unresolved, which stops chains that never end.
+    pub const MAX_REFERENCE_DEPTH: usize = 8;
+
     pub fn new() -> Self {
         Self::default()
     }
-    /// The short call to acquire a translation. Translations work through a key-value pair that are loaded based on the currently selected locale.
-    /// Here we specificially take a generic argument for the key such that any value that implements `ToString` can be translated. This creates a decent amount of flexibility for users as they will be able to "translate" custom types if they so choose.
-    pub fn tr(&self, key: impl ToString) -> String {
-        if let Some(value) = self.mappings.get(&key.to_string()).cloned() {
-            value
-        } else {
-            if cfg!(feature = "catch-missing-values") {
-                eprintln!(
-                    "missing translation value : {} has no translation value for locale {:?}",
-                    key.to_string(),
-                    self.locale
-                );
-            }
-            key.to_string()
+
+    /// Creates Translations that echo every key back without logging anything, for tests of systems that need the resource but not real translations.
+    /// No locales are available and the current locale is `und`, the code for an undetermined language. Loading data turns it into regular Translations.
+    pub fn identity() -> Self {
+        Self {
+            locale: LocaleCode::from("und"),
+            identity: true,
+            ..Default::default()
         }
     }
 
-    /// Modifies the current Translations data to load from a specified ODS file and load a particular locale.
-    #[cfg(feature = "ods")]
-    fn ods_file(&mut self, file: &Path, locale: &String) -> &mut Self {
-        // note: remember that ODS (and any other spreadsheet) will index starting at 1, not 0!!
-
-        use std::{fs::File, io::BufReader};
-
-        let Ok(f) = File::open(file) else {
-            eprintln!("Failed to locate file: {}", file.display());
-            return self;
-        };
-        let reader = BufReader::new(f);
-
-        let Ok(workbook) = spreadsheet_ods::OdsOptions::default()
-            .content_only()
-            .read_ods(reader)
-        else {
-            eprintln!("Failed to load ODS spreadsheet file at {:?}", file);
-            return self;
-        };
-        if workbook.num_sheets() == 0 {
-            eprintln!("Attempted to load empty spreadsheet file at {:?}", file);
-            return self;
-        }
-        self.path = TableFile::Ods(file.to_str().unwrap_or_default().into());
-        let sheet = workbook.sheet(0);
-        let size = sheet.used_grid_size();
+    /// Whether these are the key-echoing Translations made by `identity` with no data loaded since.
+    pub fn is_identity(&self) -> bool {
+        self.identity
+    }
 
-        self.available_locales = Vec::new();
-        for x in 0..size.1 {
-            if let Some(cell) = sheet.cell(0, x) {
-                let str_value = Self::get_cell_text(&cell);
-                if !str_value.is_empty() {
-                    self.available_locales.push(str_value.into());
-                }
-            } else {
-                eprintln!("Failed to load cell at row={}, col={}", 0, x);
+    /// Starts configuring Translations that load a source once with the final locale, see `TranslationsBuilder`.
+    pub fn builder() -> TranslationsBuilder {
+        TranslationsBuilder::default()
+    }
+    /// The short call to acquire a translation. Translations work through a key-value pair that are loaded based on the currently selected locale.
+    /// The key is borrowed through `AsRef<str>`, so `&str`, `String`, and custom key types that implement `AsRef<str>` are looked up without allocating. The key is only copied when it has no value and is echoed back.
+    /// When the current locale has no value for the key, each locale of the fallback chain is tried in order before echoing the key back.
+    /// References to other keys written as `{$key}`, such as `Welcome to {$game.title}`, are replaced with their translation, see `MAX_REFERENCE_DEPTH`.
+    pub fn tr(&self, key: impl AsRef<str>) -> String {
+        let key = key.as_ref();
+        match self.lookup(key) {
+            Some(value) if value.contains("{$") => self.resolve_references(value, &mut vec![key]),
+            Some(value) => value.clone(),
+            None => {
+                self.report_missing(key);
+                key.to_string()
             }
         }
+    }
 
-        let pref_code: LocaleCode = locale.into();
-        let locale_index: u32 = match self.available_locales.iter().position(|p| *p == pref_code) {
-            Some(index) => u32::try_from(index).unwrap_or_default(),
-            None => 0,
-        };
-        self.locale = locale.into();
-        self.mappings = HashMap::new();
-        for y in 1..size.0 {
-            let Some(key) = sheet.cell(y, 0) else {
-                continue;
-            };
-            let Some(value) = sheet.cell(y, locale_index) else {
+    /// Replaces the `{$key}` references in the value with the translation of the referenced key, resolving references inside it as well.
+    /// `stack` holds the keys being resolved. A reference back to one of them, a reference nested deeper than `MAX_REFERENCE_DEPTH`, or a missing key is left verbatim.
+    fn resolve_references<'a>(&'a self, value: &'a str, stack: &mut Vec<&'a str>) -> String {
+        if !value.contains("{$") {
+            return value.to_string();
+        }
+        let mut output = String::with_capacity(value.len());
+        let mut rest = value;
+        while let Some(start) = rest.find('{') {
+            output.push_str(&rest[..start]);
+            rest = &rest[start..];
+            // escaped braces are kept for the placeholders of `tr_args` and `tr_fmt`
+            if rest.starts_with("{{") {
+                output.push_str("{{");
+                rest = &rest[2..];
                 continue;
+            }
+            let Some(end) = rest.find('}') else {
+                break;
             };
-            self.mappings
-                .insert(Self::get_cell_text(&key), Self::get_cell_text(&value));
-        }
-        self
-    }
-
-    #[cfg(feature = "ods")]
-    fn get_cell_text(cell: &CellContent) -> String {
-        match &cell.value {
-            spreadsheet_ods::Value::Empty => "".into(),
-            spreadsheet_ods::Value::Boolean(b) => b.to_string(),
-            spreadsheet_ods::Value::Number(n) => n.to_string(),
-            spreadsheet_ods::Value::Percentage(p) => format!("{}%", p * 100.),
-            spreadsheet_ods::Value::Currency(v, c) => format!("{}{}", c, v),
-            spreadsheet_ods::Value::Text(t) => t.clone(),
-            spreadsheet_ods::Value::TextXml(x) => {
-                for tag in x {
-                    for c in tag.content() {
-                        if let spreadsheet_ods::xmltree::XmlContent::Text(t) = c {
-                            return t.clone();
-                        }
+            let reference = rest[1..end]
+                .strip_prefix('$')
+                .filter(|key| !key.is_empty())
+                .and_then(|key| {
+                    if stack.contains(&key) {
+                        warn!(
+                            "Translation key {:?} references itself through {:?}, leaving the reference unresolved",
+                            key, stack
+                        );
+                        return None;
                     }
-                }
-                "".into()
+                    if stack.len() > Self::MAX_REFERENCE_DEPTH {
+                        warn!(
+                            "References from translation key {:?} are nested deeper than {}, leaving {:?} unresolved",
+                            stack[0],
+                            Self::MAX_REFERENCE_DEPTH,
+                            key
+                        );
+                        return None;
+                    }
+                    let value = self.lookup(key)?;
+                    stack.push(key);
+                    let resolved = self.resolve_references(value, stack);
+                    stack.pop();
+                    Some(resolved)
+                });
+            match reference {
+                Some(value) => output.push_str(&value),
+                None => output.push_str(&rest[..=end]),
             }
-            spreadsheet_ods::Value::DateTime(dt) => dt.to_string(),
-            spreadsheet_ods::Value::TimeDuration(dur) => dur.to_string(),
+            rest = &rest[end + 1..];
         }
+        output.push_str(rest);
+        output
     }
 
-    /// Modifies the current Translations data to load from a specified CSV file and load a particular locale.
-    #[cfg(feature = "csv")]
-    pub fn csv_file(&mut self, path: &Path, locale: &String) -> &mut Self {
-        let Ok(mut reader) = csv::ReaderBuilder::new()
-            .has_headers(true)
-            .double_quote(false)
-            .escape(Some(b'\\'))
-            .flexible(true)
-            .from_path(path)
-        else {
-            eprintln!("Failed to load csv file: {}", path.display());
-            return self;
-        };
-        self.path = TableFile::Csv(path.to_str().unwrap_or_default().into());
+    /// Translates every key in order with the same behavior as `tr`, including echoing back keys that have no value.
+    /// Accepts slices, arrays, and any other iterator of keys.
+    pub fn tr_many<K: AsRef<str>>(&self, keys: impl IntoIterator<Item = K>) -> Vec<String> {
+        keys.into_iter().map(|key| self.tr(key)).collect()
+    }
 
-        let Ok(head) = reader.headers() else {
-            eprintln!("Failed to collect header row from reader");
-            return self;
-        };
-        let locales = head
-            .into_iter()
-            .map(|s| s.to_string().trim().into())
-            .collect::<Vec<String>>();
-        if locales.is_empty() {
-            eprintln!("Collected empty locale list!");
+    /// Logs a failed lookup when `catch-missing-values` is enabled, records it when missing keys are being tracked, and passes it to the `on_missing` callback.
+    fn report_missing(&self, key: &str) {
+        if self.identity {
+            return;
         }
-
-        let locale_index = locales.iter().position(|p| p == locale).unwrap_or_default();
-        if locale_index == 0 {
-            eprintln!(
-                "Locale index not found for locale {:?} in set {:#?}",
-                locale, locales
-            )
+        if cfg!(feature = "catch-missing-values") {
+            debug!(
+                "missing translation value : {} has no translation value for locale {:?}",
+                key, self.locale
+            );
+        }
+        if let Some(missing) = &self.missing_keys {
+            missing
+                .lock()
+                .insert((key.to_string(), self.locale_string(&self.locale)));
+        }
+        if let Some(callback) = &self.on_missing {
+            (callback.0)(key, &self.locale);
+        }
+    }
+
+    /// Registers a callback that is run with the key and current locale every time `tr` or `tr_or` fails to find a value, such as to send missing keys to analytics or a log file.
+    /// Replaces any previously registered callback. It is shared between clones of the resource and is not serialized.
+    pub fn on_missing(
+        &mut self,
+        callback: impl Fn(&str, &LocaleCode) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.on_missing = Some(MissingCallback(Arc::new(callback)));
+        self
+    }
+
+    /// Removes the callback registered with `on_missing`.
+    pub fn clear_on_missing(&mut self) -> &mut Self {
+        self.on_missing = None;
+        self
+    }
+
+    /// Sets whether keys that `tr` and `tr_or` fail to find are recorded, so they can be collected with `take_missing_keys`. Disabled by default.
+    /// Disabling tracking drops anything recorded so far.
+    pub fn track_missing_keys(&mut self, enabled: bool) -> &mut Self {
+        match (enabled, &self.missing_keys) {
+            (true, None) => self.missing_keys = Some(MissingKeys::default()),
+            (false, _) => self.missing_keys = None,
+            _ => (),
+        }
+        self
+    }
+
+    /// Drains every missing `(key, locale)` pair recorded since the last call, sorted by locale then key.
+    /// Always empty unless enabled with `track_missing_keys`.
+    pub fn take_missing_keys(&mut self) -> Vec<(String, LocaleCode)> {
+        let Some(missing) = &self.missing_keys else {
+            return Vec::new();
+        };
+        let mut keys: Vec<(String, String)> =
+            std::mem::take(&mut *missing.lock()).into_iter().collect();
+        keys.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+        keys.into_iter()
+            .map(|(key, locale)| (key, self.locale_code(locale)))
+            .collect()
+    }
+
+    /// Acquires a translation like `tr`, but returns `None` when no value exists for the key instead of echoing the key back.
+    pub fn tr_opt(&self, key: impl AsRef<str>) -> Option<String> {
+        self.lookup(key.as_ref()).cloned()
+    }
+
+    /// Acquires a translation like `tr_opt`, but borrows the stored value instead of cloning it, which avoids an allocation for UI that is translated every frame.
+    /// The key is borrowed as well, so a lookup allocates nothing unless keys are case insensitive.
+    pub fn tr_ref(&self, key: impl AsRef<str>) -> Option<&str> {
+        self.lookup(key.as_ref()).map(String::as_str)
+    }
+
+    /// Acquires a translation like `tr`, but returns the provided default when no value exists for the key.
+    /// Keys that are present but map to an empty string still return the empty string, unless the `EmptyCellPolicy` treats them as missing.
+    pub fn tr_or(&self, key: impl AsRef<str>, default: impl ToString) -> String {
+        let key = key.as_ref();
+        self.tr_opt(key).unwrap_or_else(|| {
+            self.report_missing(key);
+            default.to_string()
+        })
+    }
+
+    /// Translates the variant of a key for a context, such as a grammatical gender, stored as `key@context` (for example `left@feminine`).
+    /// When the current locale has no variant for the context, the plain key is translated as in `tr`.
+    pub fn tr_ctx(&self, key: impl AsRef<str>, context: &str) -> String {
+        let key = key.as_ref();
+        self.tr_opt(format!("{}{}{}", key, self.context_separator, context))
+            .unwrap_or_else(|| self.tr(key))
+    }
+
+    /// Sets the separator placed between a key and its context by `tr_ctx`. Defaults to `@`.
+    pub fn context_separator(&mut self, separator: impl ToString) -> &mut Self {
+        self.context_separator = separator.to_string();
+        self
+    }
+
+    /// Checks whether the current locale has a value for the key without building a translated string.
+    /// Only the current locale is checked, keys resolved through the fallback chain are not counted.
+    pub fn contains_key(&self, key: impl AsRef<str>) -> bool {
+        self.mappings
+            .contains_key(self.normalize_key(key.as_ref()).as_ref())
+    }
+
+    /// Checks a known set of keys against the loaded data, returning the keys that `tr` would echo back, in the given order.
+    /// Keys resolved through the fallback chain count as present. Meant for a startup check that fails fast when a key is renamed in the table but not in the code.
+    pub fn assert_keys(&self, keys: &[&str]) -> Vec<String> {
+        keys.iter()
+            .filter(|key| self.lookup(key).is_none())
+            .map(|key| key.to_string())
+            .collect()
+    }
+
+    /// Finds a key whose value in the current locale is exactly `value`, which is handy for tracking on-screen text back to its key.
+    /// Values are not unique, so when several keys share a value the smallest key in sorted order is returned, which keeps the result the same between runs.
+    pub fn key_for_value(&self, value: &str) -> Option<&String> {
+        self.mappings
+            .iter()
+            .filter(|(_, mapped)| mapped.as_str() == value)
+            .map(|(key, _)| key)
+            .min()
+    }
+
+    /// Finds the value for a key in the current locale, walking the fallback chain if it is missing.
+    /// Empty values are handled according to the `EmptyCellPolicy`.
+    fn lookup(&self, key: &str) -> Option<&String> {
+        let key = self.normalize_key(key);
+        let key = key.as_ref();
+        let usable =
+            |value: &&String| !value.is_empty() || self.empty_cells == EmptyCellPolicy::ReturnEmpty;
+        match self.mappings.get(key) {
+            Some(value) if usable(&value) => return Some(value),
+            Some(_) if self.empty_cells == EmptyCellPolicy::EchoKey => return None,
+            _ => (),
+        }
+        self.fallback_mappings
+            .iter()
+            .find_map(|(_, mapping)| mapping.get(key).filter(usable))
+    }
+
+    /// Sets what lookups return for keys whose value is empty, such as a cell that has not been translated yet. Defaults to `EmptyCellPolicy::ReturnEmpty`.
+    pub fn empty_cell_policy(&mut self, policy: EmptyCellPolicy) -> &mut Self {
+        self.empty_cells = policy;
+        self
+    }
+
+    /// Sets the ordered list of locales that `tr` falls back to when the current locale is missing a key, e.g. `fr-CA` then `fr` then `en`.
+    /// The columns for these locales are loaded immediately from the current source and kept in memory alongside the current locale.
+    pub fn set_fallback_chain(&mut self, locales: Vec<LocaleCode>) -> &mut Self {
+        self.fallback_chain = locales;
+        self.load_fallbacks()
+    }
+
+    /// (Re)loads the mapping for each locale of the fallback chain from the current source.
+    fn load_fallbacks(&mut self) -> &mut Self {
+        self.fallback_mappings.clear();
+        if self.path == TableFile::None {
+            return self;
+        }
+        if self.eager {
+            self.load_columns();
+        }
+        for code in self.fallback_chain.clone() {
+            if code == self.locale || !self.available_locales.contains(&code) {
+                continue;
+            }
+            let column = self.load_column(&code);
+            self.fallback_mappings.push((code, column));
+        }
+        self
+    }
+
+    /// Loads the mapping of another locale from the current source, including any merged sources, without touching this instance.
+    /// Columns already kept in memory by eager loading are reused.
+    fn load_column(&self, code: &LocaleCode) -> HashMap<String, String> {
+        if let Some(column) = self.columns.get(code) {
+            return column.clone();
+        }
+        let mut column = self.empty_like();
+        let locale = self.locale_string(code);
+        column.load_borrowed(&self.path, &locale);
+        #[cfg(any(feature = "csv", feature = "ods"))]
+        column.load_merged(self.merged.clone(), &locale);
+        column.mappings
+    }
+
+    /// Loads every available column other than the current one for eager loading.
+    /// Tables holding every locale, such as CSV and spreadsheets, are read once for all columns. Other sources are read once per column.
+    fn load_columns(&mut self) {
+        self.columns.clear();
+        #[cfg(feature = "fluent")]
+        if matches!(self.path, TableFile::Fluent(_)) {
+            // the Fluent bundle belongs to a single locale, so its columns are always loaded from the source
+            return;
+        }
+        let codes = self
+            .available_locales
+            .iter()
+            .filter(|code| **code != self.locale)
+            .cloned()
+            .collect::<Vec<_>>();
+        let columns = match self.table_columns(&codes) {
+            Some(columns) => columns,
+            None => codes.iter().map(|code| self.load_column(code)).collect(),
+        };
+        self.columns = codes.into_iter().zip(columns).collect();
+    }
+
+    /// Reads the given locale columns from the current source and its merged sources in one pass over each source.
+    /// Returns `None` when the current source is not a single table of every locale.
+    fn table_columns(&self, codes: &[LocaleCode]) -> Option<Vec<HashMap<String, String>>> {
+        let rows = self.read_columns(&self.path, codes)?;
+        let mut columns = codes.iter().map(|_| self.empty_like()).collect::<Vec<_>>();
+        for (column, rows) in columns.iter_mut().zip(rows) {
+            column.data(std::iter::empty::<String>(), rows.into_iter(), false);
+        }
+        #[cfg(any(feature = "csv", feature = "ods"))]
+        for (source, prefix) in &self.merged {
+            // merged sources are read with the default options, the same as `merge_csv` and `merge_ods`
+            let other = Translations {
+                key_prefix: prefix.clone(),
+                #[cfg(feature = "csv")]
+                csv_options: CsvOptions::default(),
+                #[cfg(feature = "ods")]
+                ods_sheet: OdsSheet::default(),
+                ..self.empty_like()
+            };
+            let Some(rows) = other.read_columns(source, codes) else {
+                continue;
+            };
+            for (column, rows) in columns.iter_mut().zip(rows) {
+                column.key_prefix = prefix.clone();
+                column.data(std::iter::empty::<String>(), rows.into_iter(), false);
+            }
+        }
+        Some(columns.into_iter().map(|column| column.mappings).collect())
+    }
+
+    /// Reads the key-value pairs of the given locale columns from a table source in one pass, in the same order as `codes`.
+    /// A column missing from the table has no pairs. Returns `None` for sources that are not a single table of every locale.
+    fn read_columns(
+        &self,
+        source: &TableFile,
+        codes: &[LocaleCode],
+    ) -> Option<Vec<Vec<(String, String)>>> {
+        let columns = match source {
+            #[cfg(feature = "csv")]
+            TableFile::Csv(path) => match self.csv_options.reader_builder().from_path(path) {
+                Ok(reader) => self.csv_columns(reader, codes),
+                Err(err) => {
+                    load_error!("Failed to read csv file at {:?}: {}", path, err);
+                    vec![Vec::new(); codes.len()]
+                }
+            },
+            #[cfg(feature = "csv")]
+            TableFile::CsvRaw(raw_data) => self.csv_columns(
+                self.csv_options
+                    .reader_builder()
+                    .from_reader(raw_data.as_bytes()),
+                codes,
+            ),
+            #[cfg(feature = "ods")]
+            TableFile::Ods(path) => match Self::read_ods_file(Path::new(path)) {
+                Some(workbook) => self.ods_columns(&workbook, path, codes),
+                None => vec![Vec::new(); codes.len()],
+            },
+            #[cfg(feature = "ods")]
+            TableFile::OdsRaw(bytes) => match Self::read_ods_bytes(bytes) {
+                Some(workbook) => self.ods_columns(&workbook, "<memory>", codes),
+                None => {
+                    load_error!("Failed to load ODS spreadsheet data from memory");
+                    vec![Vec::new(); codes.len()]
+                }
+            },
+            #[cfg(feature = "xlsx")]
+            TableFile::Xlsx(path) => self.xlsx_columns(Path::new(path), codes),
+            TableFile::Memory(table) => codes
+                .iter()
+                .map(|code| {
+                    table
+                        .iter()
+                        .find(|(name, _)| self.locale_code(name.as_str()) == *code)
+                        .map(|(_, column)| column.clone())
+                        .unwrap_or_default()
+                })
+                .collect(),
+            _ => return None,
+        };
+        Some(columns)
+    }
+
+    /// Splits table rows into the key-value pairs of each of the given columns in one pass, reading cells through `cell`.
+    /// Rows without a key are skipped, and a column gets no pair for a row that has no cell at its index.
+    #[cfg(any(feature = "csv", feature = "ods", feature = "xlsx"))]
+    fn split_columns<R>(
+        &self,
+        rows: impl Iterator<Item = R>,
+        indices: &[Option<usize>],
+        prefix: &str,
+        cell: impl Fn(&R, usize) -> Option<String>,
+    ) -> Vec<Vec<(String, String)>> {
+        let mut columns = vec![Vec::new(); indices.len()];
+        for row in rows {
+            let Some(key) = cell(&row, self.key_column) else {
+                continue;
+            };
+            let key = format!("{}{}", prefix, key);
+            for (column, index) in columns.iter_mut().zip(indices) {
+                if let Some(value) = index.and_then(|index| cell(&row, index)) {
+                    column.push((key.clone(), value));
+                }
+            }
+        }
+        columns
+    }
+
+    /// Checks that every available locale defines every key, returning each `(locale, key)` pair whose value is empty or absent.
+    /// Every locale column is loaded from the current source to compare them, so this is meant for tests and tooling rather than every frame.
+    /// Returns nothing when the data was not loaded from a source, such as data inserted with `data`.
+    pub fn validate_coverage(&self) -> Vec<(LocaleCode, String)> {
+        if self.path == TableFile::None {
+            return Vec::new();
+        }
+        let columns: Vec<(LocaleCode, HashMap<String, String>)> = self
+            .available_locales
+            .iter()
+            .map(|code| (code.clone(), self.load_column(code)))
+            .collect();
+        let mut keys: Vec<&String> = columns
+            .iter()
+            .flat_map(|(_, column)| column.keys())
+            .collect::<HashSet<&String>>()
+            .into_iter()
+            .collect();
+        keys.sort();
+
+        let mut missing = Vec::new();
+        for (code, column) in &columns {
+            for key in &keys {
+                if column.get(*key).filter(|value| !value.is_empty()).is_none() {
+                    missing.push((code.clone(), key.to_string()));
+                }
+            }
+        }
+        missing
+    }
+
+    /// Lists the keys that have a value in the current locale but are empty or absent in `target`, sorted alphabetically.
+    /// The current locale acts as the reference, so switch to the source language before handing the list to a translator.
+    pub fn missing_in(&self, target: &LocaleCode) -> Vec<String> {
+        let column = if *target == self.locale {
+            self.mappings.clone()
+        } else if self.path == TableFile::None || !self.available_locales.contains(target) {
+            HashMap::new()
+        } else {
+            self.load_column(target)
+        };
+        let mut missing: Vec<String> = self
+            .mappings
+            .iter()
+            .filter(|(_, value)| !value.is_empty())
+            .filter(|(key, _)| column.get(*key).filter(|value| !value.is_empty()).is_none())
+            .map(|(key, _)| key.clone())
+            .collect();
+        missing.sort();
+        missing
+    }
+
+    /// Creates an empty instance sharing the loading configuration of this one, used to load additional columns or sources.
+    fn empty_like(&self) -> Translations {
+        Translations {
+            key_prefix: self.key_prefix.clone(),
+            key_column: self.key_column,
+            preserve_whitespace: self.preserve_whitespace,
+            comment_prefix: self.comment_prefix.clone(),
+            region_delimiter: self.region_delimiter.clone(),
+            case_insensitive_keys: self.case_insensitive_keys,
+            #[cfg(feature = "csv")]
+            csv_options: self.csv_options.clone(),
+            #[cfg(feature = "ods")]
+            ods_sheet: self.ods_sheet.clone(),
+            #[cfg(feature = "ods")]
+            ods_cell_text: self.ods_cell_text,
+            #[cfg(feature = "ods")]
+            ods_header_row: self.ods_header_row,
+            #[cfg(feature = "ods")]
+            ods_data_row: self.ods_data_row,
+            ..Default::default()
+        }
+    }
+
+    /// Loads an additional CSV file on top of the current data without clearing it, so values from later files override earlier ones.
+    /// The available locales become the union of all sources, and merged files are loaded again whenever `use_locale` switches locales.
+    #[cfg(feature = "csv")]
+    pub fn merge_csv(&mut self, path: &Path, locale: &String) -> &mut Self {
+        self.load_csv_prefixed(path, locale, "")
+    }
+
+    /// Merges a CSV file like `merge_csv`, prepending `prefix` to every key it inserts. Loading `ui.csv` with the prefix `ui.` makes its `play` key available as `ui.play`, isolated from other namespaces.
+    #[cfg(feature = "csv")]
+    pub fn load_csv_prefixed(&mut self, path: &Path, locale: &String, prefix: &str) -> &mut Self {
+        let mut other = Translations {
+            fallback_chain: self.fallback_chain.clone(),
+            key_prefix: prefix.into(),
+            ..self.empty_like()
+        };
+        other.csv_file(path, locale);
+        self.merge_from(other)
+    }
+
+    /// Loads an additional ODS file on top of the current data without clearing it, so values from later files override earlier ones.
+    /// The available locales become the union of all sources, and merged files are loaded again whenever `use_locale` switches locales.
+    #[cfg(feature = "ods")]
+    pub fn merge_ods(&mut self, path: &Path, locale: &String) -> &mut Self {
+        self.load_ods_prefixed(path, locale, "")
+    }
+
+    /// Merges an ODS file like `merge_ods`, prepending `prefix` to every key it inserts.
+    #[cfg(feature = "ods")]
+    pub fn load_ods_prefixed(&mut self, path: &Path, locale: &String, prefix: &str) -> &mut Self {
+        let mut other = Translations {
+            fallback_chain: self.fallback_chain.clone(),
+            key_prefix: prefix.into(),
+            ..self.empty_like()
+        };
+        other.ods_file(path, locale);
+        self.merge_from(other)
+    }
+
+    /// Merges the data of a separately loaded source into this instance and remembers the source for reloading.
+    #[cfg(any(feature = "csv", feature = "ods"))]
+    fn merge_from(&mut self, other: Translations) -> &mut Self {
+        if other.path == TableFile::None {
+            return self;
+        }
+        for code in other.available_locales {
+            if !self.available_locales.contains(&code) {
+                self.available_locales.push(code);
+            }
+        }
+        self.mappings.extend(other.mappings);
+        for (code, mapping) in other.fallback_mappings {
+            match self.fallback_mappings.iter_mut().find(|(c, _)| *c == code) {
+                Some((_, existing)) => existing.extend(mapping),
+                None => self.fallback_mappings.push((code, mapping)),
+            }
+        }
+        let source = (other.path, other.key_prefix);
+        if !self.merged.contains(&source) {
+            self.merged.push(source);
+        }
+        if self.eager {
+            self.load_columns();
+        }
+        self
+    }
+
+    /// Translates the key and then replaces any `{name}` placeholders in the value with the matching entry from `args`.
+    /// Placeholders without a matching argument are left verbatim so typos are easy to spot, and a literal `{{` produces a single `{`.
+    /// With `with_cache`, results are remembered so repeated calls with the same arguments skip the interpolation.
+    pub fn tr_args(&self, key: impl AsRef<str>, args: &HashMap<String, String>) -> String {
+        let key = key.as_ref();
+        match (&self.args_cache, self.lookup(key)) {
+            // values referencing other keys can change without their own template changing, so they are not cached
+            (Some(cache), Some(template)) if !template.contains("{$") => {
+                if let Some(result) = cache.get(key, template, args) {
+                    return result;
+                }
+                let result = Self::interpolate(template, |name| args.get(name).cloned());
+                cache.insert(key, template, args, result.clone());
+                result
+            }
+            _ => Self::interpolate(&self.tr(key), |name| args.get(name).cloned()),
+        }
+    }
+
+    /// Remembers up to `capacity` results of `tr_args`, evicting the least recently used ones, for text such as HUD counters that is rebuilt every frame with the same arguments.
+    /// The cache is emptied when switching locales, and a result is only reused while its key still translates to the same value. A capacity of 0 disables the cache, which is the default.
+    pub fn with_cache(&mut self, capacity: usize) -> &mut Self {
+        self.args_cache = (capacity > 0).then(|| cache::ArgsCache::new(capacity));
+        self
+    }
+
+    /// Translates the key and then replaces positional `{0}`, `{1}`, ... placeholders with the matching entry from `args`.
+    /// An empty `{}` consumes the next argument in order. Indices outside of `args` are left verbatim rather than panicking.
+    pub fn tr_fmt(&self, key: impl AsRef<str>, args: &[&str]) -> String {
+        let mut next = 0;
+        Self::interpolate(&self.tr(key), |name| {
+            let index = if name.is_empty() {
+                next += 1;
+                next - 1
+            } else {
+                name.parse::<usize>().ok()?
+            };
+            args.get(index).map(|arg| arg.to_string())
+        })
+    }
+
+    /// Replaces `{placeholder}` tokens in the template using the provided lookup. Unresolved placeholders are kept as is.
+    fn interpolate(template: &str, mut lookup: impl FnMut(&str) -> Option<String>) -> String {
+        let mut output = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            output.push_str(&rest[..start]);
+            rest = &rest[start..];
+            if rest.starts_with("{{") {
+                output.push('{');
+                rest = &rest[2..];
+                continue;
+            }
+            let Some(end) = rest.find('}') else {
+                break;
+            };
+            let name = &rest[1..end];
+            match lookup(name) {
+                Some(value) => output.push_str(&value),
+                None => output.push_str(&rest[..=end]),
+            }
+            rest = &rest[end + 1..];
+        }
+        output.push_str(rest);
+        output
+    }
+
+    /// Modifies the current Translations data to load from a specified ODS file and load a particular locale.
+    /// Only the first sheet is read. Use `ods_file_sheet` to choose a different sheet.
+    #[cfg(feature = "ods")]
+    pub fn ods_file(&mut self, file: &Path, locale: &String) -> &mut Self {
+        self.ods_file_sheet(file, locale, OdsSheet::default())
+    }
+
+    /// Modifies the current Translations data to load from the selected sheet(s) of an ODS file and load a particular locale.
+    /// A sheet can be selected by index or by name, e.g. `ods_file_sheet(path, locale, "dialogue")`. A named sheet that does not exist falls back to the first sheet.
+    /// The selection is kept for reloading the file when switching locales.
+    #[cfg(feature = "ods")]
+    pub fn ods_file_sheet(
+        &mut self,
+        file: &Path,
+        locale: &String,
+        sheet: impl Into<OdsSheet>,
+    ) -> &mut Self {
+        let Some(workbook) = Self::read_ods_file(file) else {
+            return self;
+        };
+        let source = TableFile::Ods(file.to_str().unwrap_or_default().into());
+        self.ods_workbook(workbook, source, locale, sheet.into())
+    }
+
+    /// Opens and reads an ODS file, logging any failure.
+    #[cfg(feature = "ods")]
+    fn read_ods_file(file: &Path) -> Option<spreadsheet_ods::WorkBook> {
+        use std::{fs::File, io::BufReader};
+
+        let Ok(f) = File::open(file) else {
+            load_error!("Failed to locate file: {}", file.display());
+            return None;
+        };
+        let workbook = spreadsheet_ods::OdsOptions::default()
+            .content_only()
+            .read_ods(BufReader::new(f))
+            .ok();
+        if workbook.is_none() {
+            load_error!("Failed to load ODS spreadsheet file at {:?}", file);
+        }
+        workbook
+    }
+
+    /// Creates Translations from an ODS file compiled into the binary, for platforms without a filesystem such as WASM.
+    /// The bytes are kept in memory so `use_locale` can switch locales without reading any files.
+    /// ```ignore
+    /// let translations = Translations::from_embedded_ods(include_bytes!("../assets/lang.ods"), "en");
+    /// ```
+    #[cfg(feature = "ods")]
+    pub fn from_embedded_ods(ods_data: &[u8], locale: impl ToString) -> Self {
+        Self::default()
+            .ods_raw(ods_data.to_vec(), &locale.to_string())
+            .build()
+    }
+
+    /// Modifies the current Translations data to load from the bytes of an ODS file held in memory, such as a downloaded file or one embedded with `include_bytes!`, and load a particular locale.
+    /// The bytes are kept so `use_locale` can switch locales without any files, the same as `csv_raw`. The sheet set by `ods_file_sheet` is used.
+    #[cfg(feature = "ods")]
+    pub fn ods_raw(&mut self, bytes: Vec<u8>, locale: &String) -> &mut Self {
+        let sheet = self.ods_sheet.clone();
+        self.ods_bytes(bytes, locale, sheet)
+    }
+
+    /// Modifies the current Translations data to load an ODS file from any reader, such as a file inside an archive or a network stream, and load a particular locale.
+    /// The reader is read to the end and its bytes are kept in memory, so `use_locale` can switch locales without reading it again. The sheet set by `ods_file_sheet` is used.
+    #[cfg(feature = "ods")]
+    pub fn ods_from_reader(
+        &mut self,
+        mut reader: impl std::io::Read,
+        locale: &String,
+    ) -> &mut Self {
+        let mut bytes = Vec::new();
+        if let Err(err) = reader.read_to_end(&mut bytes) {
+            load_error!("Failed to read ODS spreadsheet data: {}", err);
+            return self;
+        }
+        self.ods_raw(bytes, locale)
+    }
+
+    /// Modifies the current Translations data to load a CSV or ODS table stored inside a zip archive and load a particular locale.
+    /// The format is detected from the extension of `inner_path`. Only that entry is extracted, into memory, and it is kept there so `use_locale` can switch locales without opening the archive again.
+    #[cfg(all(feature = "zip", any(feature = "csv", feature = "ods")))]
+    pub fn load_from_zip(
+        &mut self,
+        archive: &Path,
+        inner_path: &str,
+        locale: &String,
+    ) -> &mut Self {
+        use std::io::Read;
+
+        let mut zip = match std::fs::File::open(archive)
+            .map_err(zip::result::ZipError::from)
+            .and_then(zip::ZipArchive::new)
+        {
+            Ok(zip) => zip,
+            Err(err) => {
+                load_error!("Failed to open zip archive {}: {}", archive.display(), err);
+                return self;
+            }
+        };
+        let mut bytes = Vec::new();
+        if let Err(err) = zip
+            .by_name(inner_path)
+            .map_err(std::io::Error::from)
+            .and_then(|mut entry| entry.read_to_end(&mut bytes))
+        {
+            load_error!(
+                "Failed to read {:?} from zip archive {}: {}",
+                inner_path,
+                archive.display(),
+                err
+            );
+            return self;
+        }
+        let extension = Path::new(inner_path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+        match extension.as_str() {
+            #[cfg(feature = "csv")]
+            "csv" => match String::from_utf8(bytes) {
+                Ok(text) => self.csv_raw(text, locale),
+                Err(err) => {
+                    load_error!("Failed to read {:?} as UTF-8 text: {}", inner_path, err);
+                    self
+                }
+            },
+            #[cfg(feature = "ods")]
+            "ods" => self.ods_raw(bytes, locale),
+            _ => {
+                load_error!(
+                    "Unsupported translation table extension {:?} in zip archive",
+                    extension
+                );
+                self
+            }
+        }
+    }
+
+    /// Modifies the current Translations data to load from the bytes of an ODS file held in memory, keeping the bytes for switching locales.
+    #[cfg(feature = "ods")]
+    pub(crate) fn ods_bytes(
+        &mut self,
+        bytes: Vec<u8>,
+        locale: &String,
+        sheet: impl Into<OdsSheet>,
+    ) -> &mut Self {
+        let Some(workbook) = Self::read_ods_bytes(&bytes) else {
+            load_error!("Failed to load ODS spreadsheet data from memory");
+            return self;
+        };
+        self.ods_workbook(workbook, TableFile::OdsRaw(bytes), locale, sheet.into())
+    }
+
+    #[cfg(feature = "ods")]
+    fn read_ods_bytes(bytes: &[u8]) -> Option<spreadsheet_ods::WorkBook> {
+        spreadsheet_ods::OdsOptions::default()
+            .content_only()
+            .read_ods(std::io::Cursor::new(bytes))
+            .ok()
+    }
+
+    /// Loads the selected sheet(s) of an opened workbook, recording `source` for reloading when switching locales.
+    #[cfg(feature = "ods")]
+    fn ods_workbook(
+        &mut self,
+        workbook: spreadsheet_ods::WorkBook,
+        source: TableFile,
+        locale: &String,
+        sheet: OdsSheet,
+    ) -> &mut Self {
+        let file = match &source {
+            TableFile::Ods(path) => path.clone(),
+            _ => "<memory>".to_string(),
+        };
+        if workbook.num_sheets() == 0 {
+            warn!("Attempted to load empty spreadsheet file at {:?}", file);
+            return self;
+        }
+        self.path = source;
+        self.ods_sheet = sheet;
+        self.locale = self.locale_code(locale);
+
+        let mut sheets = self.ods_selected_sheets(&workbook, &file).into_iter();
+        let (mut locales, mut mapping) = match sheets.next() {
+            Some((sheet, prefix)) => self.ods_sheet_data(sheet, &prefix),
+            None => Default::default(),
+        };
+        for (sheet, prefix) in sheets {
+            let (sheet_locales, sheet_mapping) = self.ods_sheet_data(sheet, &prefix);
+            for code in sheet_locales {
+                if !locales.contains(&code) {
+                    locales.push(code);
+                }
+            }
+            mapping.extend(sheet_mapping);
         }
-        self.locale = locale.into();
-        let mapping = reader
-            .records()
-            .map(|p| {
-                let rec = p.unwrap_or_default();
-                (
-                    rec.get(0).unwrap_or_default().to_string(),
-                    rec.get(locale_index).unwrap_or_default().to_string(),
-                )
-            })
-            .collect::<Vec<(String, String)>>();
         self.data(locales.into_iter(), mapping.into_iter(), true)
+            .load_fallbacks()
+    }
+
+    /// The sheets of the workbook selected by `ods_sheet`, each with the prefix for its keys. A sheet that does not exist falls back to the first sheet.
+    #[cfg(feature = "ods")]
+    fn ods_selected_sheets<'a>(
+        &self,
+        workbook: &'a spreadsheet_ods::WorkBook,
+        file: &str,
+    ) -> Vec<(&'a spreadsheet_ods::Sheet, String)> {
+        match &self.ods_sheet {
+            OdsSheet::Index(index) if *index < workbook.num_sheets() => {
+                vec![(workbook.sheet(*index), String::new())]
+            }
+            OdsSheet::Name(name) if workbook.sheet_idx(name).is_some() => {
+                let index = workbook.sheet_idx(name).unwrap_or_default();
+                vec![(workbook.sheet(index), String::new())]
+            }
+            OdsSheet::AllPrefixed => workbook
+                .iter_sheets()
+                .map(|sheet| (sheet, format!("{}.", sheet.name())))
+                .collect(),
+            missing => {
+                warn!(
+                    "Sheet {:?} not found in {:?}, falling back to the first sheet",
+                    missing, file
+                );
+                workbook
+                    .iter_sheets()
+                    .take(1)
+                    .map(|sheet| (sheet, String::new()))
+                    .collect()
+            }
+        }
+    }
+
+    /// Reads the given locale columns from every selected sheet of the workbook, with one pass over the rows of each sheet.
+    #[cfg(feature = "ods")]
+    fn ods_columns(
+        &self,
+        workbook: &spreadsheet_ods::WorkBook,
+        file: &str,
+        codes: &[LocaleCode],
+    ) -> Vec<Vec<(String, String)>> {
+        let mut columns = vec![Vec::new(); codes.len()];
+        if workbook.num_sheets() == 0 {
+            return columns;
+        }
+        for (sheet, prefix) in self.ods_selected_sheets(workbook, file) {
+            let headers = self.ods_headers(sheet);
+            let indices = codes
+                .iter()
+                .map(|code| self.locale_column(&headers, code))
+                .collect::<Vec<_>>();
+            for (column, rows) in columns
+                .iter_mut()
+                .zip(self.ods_rows(sheet, &prefix, &indices))
+            {
+                column.extend(rows);
+            }
+        }
+        columns
+    }
+
+    /// Collects the locales from the header row of the sheet and the key-value pairs of the current locale, prepending the prefix to every key.
+    #[cfg(feature = "ods")]
+    fn ods_sheet_data(
+        &self,
+        sheet: &spreadsheet_ods::Sheet,
+        prefix: &str,
+    ) -> (Vec<String>, Vec<(String, String)>) {
+        let headers = self.ods_headers(sheet);
+        let locales = self
+            .header_locales(headers.clone())
+            .into_iter()
+            .filter(|header| !header.is_empty())
+            .collect::<Vec<_>>();
+
+        // an empty locale only collects the header row, such as for the asset loader
+        let Some(locale_index) = (!self.locale.lang.is_empty())
+            .then(|| self.locale_column(&headers, &self.locale))
+            .flatten()
+        else {
+            if !self.locale.lang.is_empty() {
+                warn!(
+                    "Locale {:?} not found in sheet {:?}, found {:?}",
+                    self.locale,
+                    sheet.name(),
+                    locales
+                );
+            }
+            return (locales, Vec::new());
+        };
+        let mapping = self
+            .ods_rows(sheet, prefix, &[Some(locale_index)])
+            .pop()
+            .unwrap_or_default();
+        (locales, mapping)
+    }
+
+    /// The text of every cell in the header row of the sheet. Empty headers are kept so the position of each header is its column.
+    #[cfg(feature = "ods")]
+    fn ods_headers(&self, sheet: &spreadsheet_ods::Sheet) -> Vec<String> {
+        // note: remember that ODS (and any other spreadsheet) will index starting at 1, not 0!!
+        let header_row = u32::try_from(self.ods_header_row).unwrap_or_default();
+        (0..sheet.used_grid_size().1)
+            .map(|x| {
+                sheet
+                    .cell(header_row, x)
+                    .map(|cell| self.get_cell_text(&cell))
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// Reads the key-value pairs of the given columns from the data rows of the sheet in one pass, prepending the prefix to every key.
+    #[cfg(feature = "ods")]
+    fn ods_rows(
+        &self,
+        sheet: &spreadsheet_ods::Sheet,
+        prefix: &str,
+        indices: &[Option<usize>],
+    ) -> Vec<Vec<(String, String)>> {
+        let data_row = match self.ods_data_row {
+            Some(row) => u32::try_from(row).unwrap_or_default(),
+            None => u32::try_from(self.ods_header_row).unwrap_or_default() + 1,
+        };
+        self.split_columns(
+            data_row..sheet.used_grid_size().0,
+            indices,
+            prefix,
+            |y, x| {
+                let cell = sheet.cell(*y, u32::try_from(x).ok()?)?;
+                Some(self.get_cell_text(&cell))
+            },
+        )
+    }
+
+    #[cfg(feature = "ods")]
+    fn get_cell_text(&self, cell: &CellContent) -> String {
+        let raw = self.ods_cell_text == OdsCellText::Raw;
+        match &cell.value {
+            spreadsheet_ods::Value::Empty => "".into(),
+            spreadsheet_ods::Value::Boolean(b) => b.to_string(),
+            spreadsheet_ods::Value::Number(n) => n.to_string(),
+            // the displayed text of typed cells is not kept by the reader, so raw cells lose their percent sign and currency symbol
+            spreadsheet_ods::Value::Percentage(p) if raw => p.to_string(),
+            spreadsheet_ods::Value::Percentage(p) => format!("{}%", p * 100.),
+            spreadsheet_ods::Value::Currency(v, _) if raw => v.to_string(),
+            spreadsheet_ods::Value::Currency(v, c) => format!("{}{}", c, v),
+            spreadsheet_ods::Value::Text(t) => t.clone(),
+            spreadsheet_ods::Value::TextXml(x) => {
+                let mut text = String::new();
+                for (index, paragraph) in x.iter().enumerate() {
+                    if index > 0 {
+                        text.push('\n');
+                    }
+                    Self::push_xml_text(paragraph, &mut text);
+                }
+                text
+            }
+            spreadsheet_ods::Value::DateTime(dt) => dt.to_string(),
+            spreadsheet_ods::Value::TimeDuration(dur) => dur.to_string(),
+        }
+    }
+
+    /// Appends every text run of a rich text cell in document order, so runs split by inline formatting such as bold or italic are joined back together.
+    /// The `text:s`, `text:tab`, and `text:line-break` elements are turned back into the whitespace they stand for.
+    #[cfg(feature = "ods")]
+    fn push_xml_text(tag: &spreadsheet_ods::xmltree::XmlTag, out: &mut String) {
+        use spreadsheet_ods::xmltree::XmlContent;
+
+        for content in tag.content() {
+            match content {
+                XmlContent::Text(t) => out.push_str(t),
+                XmlContent::Tag(t) => match t.name() {
+                    "text:s" => {
+                        let count = t
+                            .get_attr("text:c")
+                            .and_then(|c| c.parse().ok())
+                            .unwrap_or(1);
+                        out.push_str(&" ".repeat(count));
+                    }
+                    "text:tab" => out.push('\t'),
+                    "text:line-break" => out.push('\n'),
+                    _ => Self::push_xml_text(t, out),
+                },
+            }
+        }
+    }
+
+    /// Modifies the current Translations data to load from a specified Excel (xlsx) file and load a particular locale.
+    /// Like ODS files, the first row of the first sheet holds the locales and the first column holds the keys.
+    #[cfg(feature = "xlsx")]
+    pub fn xlsx_file(&mut self, file: &Path, locale: &String) -> &mut Self {
+        let Some(sheet) = Self::read_xlsx_sheet(file) else {
+            return self;
+        };
+        self.path = TableFile::Xlsx(file.to_str().unwrap_or_default().into());
+        let mut rows = sheet.rows();
+
+        // empty headers are kept so the position of each header is its column
+        let locales = rows
+            .next()
+            .unwrap_or_default()
+            .iter()
+            .map(Self::get_xlsx_cell_text)
+            .collect::<Vec<String>>();
+
+        let pref_code = self.locale_code(locale);
+        let Some(locale_index) = self.locale_column(&locales, &pref_code) else {
+            warn!(
+                "Locale {:?} not found in {:?}, found {:?}",
+                locale, file, locales
+            );
+            return self.data(
+                self.header_locales(locales).into_iter(),
+                std::iter::empty(),
+                true,
+            );
+        };
+        self.locale = pref_code;
+        let mapping = self
+            .xlsx_rows(rows, &[Some(locale_index)])
+            .pop()
+            .unwrap_or_default();
+        self.data(
+            self.header_locales(locales).into_iter(),
+            mapping.into_iter(),
+            true,
+        )
+        .load_fallbacks()
+    }
+
+    /// Opens an xlsx file and reads its first sheet, logging any failure.
+    #[cfg(feature = "xlsx")]
+    fn read_xlsx_sheet(file: &Path) -> Option<calamine::Range<calamine::Data>> {
+        use calamine::Reader;
+
+        let mut workbook: calamine::Xlsx<_> = match calamine::open_workbook(file) {
+            Ok(workbook) => workbook,
+            Err(err) => {
+                load_error!(
+                    "Failed to load xlsx spreadsheet file at {:?}: {}",
+                    file,
+                    err
+                );
+                return None;
+            }
+        };
+        match workbook.worksheet_range_at(0) {
+            Some(Ok(sheet)) => Some(sheet),
+            Some(Err(err)) => {
+                load_error!("Failed to read xlsx worksheet in {:?}: {}", file, err);
+                None
+            }
+            None => {
+                warn!("Attempted to load empty spreadsheet file at {:?}", file);
+                None
+            }
+        }
+    }
+
+    /// Reads the given locale columns of an xlsx file in one pass over its rows.
+    #[cfg(feature = "xlsx")]
+    fn xlsx_columns(&self, file: &Path, codes: &[LocaleCode]) -> Vec<Vec<(String, String)>> {
+        let Some(sheet) = Self::read_xlsx_sheet(file) else {
+            return vec![Vec::new(); codes.len()];
+        };
+        let mut rows = sheet.rows();
+        let headers = rows
+            .next()
+            .unwrap_or_default()
+            .iter()
+            .map(Self::get_xlsx_cell_text)
+            .collect::<Vec<String>>();
+        let indices = codes
+            .iter()
+            .map(|code| self.locale_column(&headers, code))
+            .collect::<Vec<_>>();
+        self.xlsx_rows(rows, &indices)
+    }
+
+    /// Reads the key-value pairs of the given columns from the data rows of an xlsx sheet in one pass.
+    #[cfg(feature = "xlsx")]
+    fn xlsx_rows<'a>(
+        &self,
+        rows: impl Iterator<Item = &'a [calamine::Data]>,
+        indices: &[Option<usize>],
+    ) -> Vec<Vec<(String, String)>> {
+        self.split_columns(rows, indices, "", |row, index| {
+            row.get(index).map(Self::get_xlsx_cell_text)
+        })
+    }
+
+    #[cfg(feature = "xlsx")]
+    fn get_xlsx_cell_text(cell: &calamine::Data) -> String {
+        match cell {
+            calamine::Data::Empty | calamine::Data::Error(_) => "".into(),
+            calamine::Data::String(s)
+            | calamine::Data::DateTimeIso(s)
+            | calamine::Data::DurationIso(s) => s.clone(),
+            other => other.to_string(),
+        }
+    }
+
+    /// Modifies the current Translations data to load from a specified CSV file and load a particular locale.
+    /// Any failure is logged as an error and otherwise ignored. Use `try_csv_file` if you need to handle load errors yourself.
+    #[cfg(feature = "csv")]
+    pub fn csv_file(&mut self, path: &Path, locale: &String) -> &mut Self {
+        self.csv_file_with_options(path, locale, CsvOptions::default())
+    }
+
+    /// Modifies the current Translations data to load from a specified CSV file using the given parsing options and load a particular locale.
+    /// The options are kept for reloading the file when switching locales.
+    #[cfg(feature = "csv")]
+    pub fn csv_file_with_options(
+        &mut self,
+        path: &Path,
+        locale: &String,
+        options: CsvOptions,
+    ) -> &mut Self {
+        if let Err(err) = self.try_csv_file_with_options(path, locale, options) {
+            load_error!("Failed to load csv file {}: {}", path.display(), err);
+        }
+        self
+    }
+
+    /// Modifies the current Translations data to load from a specified CSV file and load a particular locale, reporting any failure as a `TranslationError`.
+    /// When the file loads but the requested locale is missing, the source and available locales are still recorded so `use_locale` can switch to a valid one.
+    #[cfg(feature = "csv")]
+    pub fn try_csv_file(
+        &mut self,
+        path: &Path,
+        locale: &String,
+    ) -> Result<&mut Self, TranslationError> {
+        self.try_csv_file_with_options(path, locale, CsvOptions::default())
+    }
+
+    /// The same as `try_csv_file`, but using the given parsing options.
+    #[cfg(feature = "csv")]
+    pub fn try_csv_file_with_options(
+        &mut self,
+        path: &Path,
+        locale: &String,
+        options: CsvOptions,
+    ) -> Result<&mut Self, TranslationError> {
+        let mut reader =
+            options
+                .reader_builder()
+                .from_path(path)
+                .map_err(|err| match err.kind() {
+                    csv::ErrorKind::Io(io) if io.kind() == std::io::ErrorKind::NotFound => {
+                        TranslationError::FileNotFound(path.to_path_buf())
+                    }
+                    _ => TranslationError::ParseError(err),
+                })?;
+
+        let locales = self.csv_headers(&mut reader, &options)?;
+        if locales.iter().all(|s| s.is_empty()) {
+            return Err(TranslationError::EmptyHeader);
+        }
+        self.path = TableFile::Csv(path.to_str().unwrap_or_default().into());
+        self.csv_options = options;
+
+        let Some(locale_index) = self.locale_column(&locales, &self.locale_code(locale)) else {
+            self.data(
+                self.header_locales(locales).into_iter(),
+                std::iter::empty(),
+                true,
+            );
+            return Err(TranslationError::LocaleNotFound(locale.clone()));
+        };
+        self.locale = self.locale_code(locale);
+        self.csv_records(&mut reader, locales, locale_index)?;
+        Ok(self.load_fallbacks())
+    }
+
+    /// Streams the key and locale column of every remaining record straight into the mappings, reusing a single record buffer instead of collecting the rows first.
+    /// Reading stops at the first malformed record, keeping the rows read before it.
+    #[cfg(feature = "csv")]
+    fn csv_records<R: std::io::Read>(
+        &mut self,
+        reader: &mut csv::Reader<R>,
+        locales: Vec<String>,
+        locale_index: usize,
+    ) -> Result<(), csv::Error> {
+        let locales = self.header_locales(locales);
+        let key_column = self.key_column;
+        let report_short_rows = self.csv_options.report_short_rows;
+        let mut short_rows = Vec::new();
+        let mut record = csv::StringRecord::new();
+        let mut error = None;
+        let rows = std::iter::from_fn(|| loop {
+            match reader.read_record(&mut record) {
+                Ok(true) => {
+                    // rows too short to reach the locale column have no value, matching missing ODS cells
+                    if let (Some(key), Some(value)) =
+                        (record.get(key_column), record.get(locale_index))
+                    {
+                        return Some((key.to_string(), value.to_string()));
+                    }
+                    if report_short_rows {
+                        short_rows.push(record.position().map_or(0, |p| p.line()));
+                    }
+                }
+                Ok(false) => return None,
+                Err(err) => {
+                    error = Some(err);
+                    return None;
+                }
+            }
+        });
+        self.data(locales.into_iter(), rows, true);
+        if !short_rows.is_empty() {
+            warn!(
+                "CSV rows {:?} have fewer than {} fields and no value for locale {:?}",
+                short_rows,
+                locale_index.max(key_column) + 1,
+                self.locale
+            );
+        }
+        self.short_rows = short_rows;
+        error.map_or(Ok(()), Err)
+    }
+
+    /// Reads the given locale columns of CSV data in one pass over its records. Reading stops at the first malformed record.
+    #[cfg(feature = "csv")]
+    fn csv_columns<R: std::io::Read>(
+        &self,
+        mut reader: csv::Reader<R>,
+        codes: &[LocaleCode],
+    ) -> Vec<Vec<(String, String)>> {
+        let Ok(headers) = self.csv_headers(&mut reader, &self.csv_options) else {
+            load_error!("Failed to collect header row from reader");
+            return vec![Vec::new(); codes.len()];
+        };
+        let indices = codes
+            .iter()
+            .map(|code| self.locale_column(&headers, code))
+            .collect::<Vec<_>>();
+        self.split_columns(
+            reader.records().map_while(Result::ok),
+            &indices,
+            "",
+            |record, index| record.get(index).map(str::to_string),
+        )
+    }
+
+    /// The line numbers of the CSV rows that were too short to reach the locale column the last time a CSV table was loaded.
+    /// Only collected when `CsvOptions::report_short_rows` is enabled.
+    #[cfg(feature = "csv")]
+    pub fn short_rows(&self) -> &[u64] {
+        &self.short_rows
+    }
+
+    /// Creates Translations from CSV data compiled into the binary, for platforms without a filesystem such as WASM.
+    /// The data is kept in memory so `use_locale` can switch locales without reading any files.
+    /// ```ignore
+    /// let translations = Translations::from_embedded_csv(include_str!("../assets/lang.csv"), "en");
+    /// ```
+    #[cfg(feature = "csv")]
+    pub fn from_embedded_csv(csv_data: &str, locale: impl ToString) -> Self {
+        Self::default()
+            .csv_raw(csv_data.into(), &locale.to_string())
+            .build()
+    }
+
+    /// Modifies the current Translations data to load from a raw string in CSV format and load a particular locale.
+    #[cfg(feature = "csv")]
+    pub fn csv_raw(&mut self, csv_data: String, locale: &String) -> &mut Self {
+        self.csv_raw_with_options(csv_data, locale, CsvOptions::default())
+    }
+
+    /// Modifies the current Translations data to load from a raw string in CSV format using the given parsing options and load a particular locale.
+    #[cfg(feature = "csv")]
+    pub fn csv_raw_with_options(
+        &mut self,
+        csv_data: String,
+        locale: &String,
+        options: CsvOptions,
+    ) -> &mut Self {
+        let loaded = self.csv_str(&csv_data, locale, options);
+        // the data is moved rather than cloned, large embedded tables would otherwise be kept twice
+        self.path = TableFile::CsvRaw(csv_data);
+        if loaded {
+            self.load_fallbacks()
+        } else {
+            self
+        }
+    }
+
+    /// Parses CSV data held in memory without recording it as the source. Returns whether the locale was found and loaded.
+    #[cfg(feature = "csv")]
+    fn csv_str(&mut self, csv_data: &str, locale: &String, options: CsvOptions) -> bool {
+        let mut reader = options.reader_builder().from_reader(csv_data.as_bytes());
+
+        let Ok(locales) = self.csv_headers(&mut reader, &options) else {
+            load_error!("Failed to collect header row from reader");
+            self.csv_options = options;
+            return false;
+        };
+        self.csv_options = options;
+        if locales.is_empty() {
+            warn!("Collected empty locale list!");
+        }
+
+        // an empty locale only collects the header row, such as for the asset loader
+        let locale_index = (!locale.is_empty())
+            .then(|| self.locale_column(&locales, &self.locale_code(locale)))
+            .flatten();
+        let Some(locale_index) = locale_index else {
+            if !locale.is_empty() {
+                warn!(
+                    "Locale index not found for locale {:?} in set {:#?}",
+                    locale, locales
+                );
+            }
+            self.data(
+                self.header_locales(locales).into_iter(),
+                std::iter::empty(),
+                true,
+            );
+            return false;
+        };
+        self.locale = self.locale_code(locale);
+        if let Err(err) = self.csv_records(&mut reader, locales, locale_index) {
+            load_error!("Failed to read csv data: {}", err);
+        }
+        true
+    }
+
+    /// Finds the column of a locale in a header row. Headers are compared as `LocaleCode`s, so `EN` and `en` match.
+    /// The header of the key column is never matched. When several headers match the locale, the first one is used and a warning is logged.
+    #[cfg(any(feature = "csv", feature = "ods", feature = "xlsx"))]
+    fn locale_column(&self, headers: &[String], locale: &LocaleCode) -> Option<usize> {
+        let mut matches = headers
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != self.key_column)
+            .filter(|(_, header)| self.locale_code(header.as_str()) == *locale);
+        let (index, _) = matches.next()?;
+        let others = matches.map(|(_, header)| header).collect::<Vec<_>>();
+        if !others.is_empty() {
+            warn!(
+                "Locale {:?} matches the header {:?} as well as {:?}, using the first one",
+                locale, headers[index], others
+            );
+        }
+        Some(index)
+    }
+
+    /// The headers of a table's locale columns, leaving out the header of the key column.
+    #[cfg(any(feature = "csv", feature = "ods", feature = "xlsx"))]
+    fn header_locales(&self, headers: Vec<String>) -> Vec<String> {
+        headers
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| *index != self.key_column)
+            .map(|(_, header)| header)
+            .collect()
+    }
+
+    /// Collects the header of every column, either from the header row or from the locales given in the options, leaving the key column without a header.
+    #[cfg(feature = "csv")]
+    fn csv_headers<R: std::io::Read>(
+        &self,
+        reader: &mut csv::Reader<R>,
+        options: &CsvOptions,
+    ) -> Result<Vec<String>, csv::Error> {
+        if options.locales.is_empty() {
+            return Ok(Self::csv_header_locales(reader.headers()?));
+        }
+        let mut headers = options
+            .locales
+            .iter()
+            .map(|locale| locale.trim().to_string())
+            .collect::<Vec<_>>();
+        headers.insert(self.key_column.min(headers.len()), String::new());
+        Ok(headers)
+    }
+
+    /// Collects the trimmed locale codes of a CSV header row. A leading UTF-8 byte order mark, as written by Excel on Windows, is removed from the first cell.
+    #[cfg(feature = "csv")]
+    fn csv_header_locales(head: &csv::StringRecord) -> Vec<String> {
+        head.into_iter()
+            .enumerate()
+            .map(|(index, s)| {
+                let s = if index == 0 {
+                    s.trim_start_matches('\u{FEFF}')
+                } else {
+                    s
+                };
+                s.trim().into()
+            })
+            .collect()
+    }
+
+    /// Modifies the current Translations data to load from a directory of JSON files and load a particular locale.
+    /// Each file is named after its locale (`dir/en.json`, `dir/es.json`, ...) and the available locales are collected from those file names.
+    /// Nested objects are flattened using a `.` separator, so `{ "menu": { "play": "Play" } }` produces the key `menu.play`.
+    #[cfg(feature = "json")]
+    pub fn json_file(&mut self, dir: &Path, locale: &String) -> &mut Self {
+        let Some(locales) = Self::scan_locale_dir(dir, "json") else {
+            return self;
+        };
+        self.path = TableFile::Json(dir.to_str().unwrap_or_default().into());
+
+        let file = dir.join(format!("{}.json", locale));
+        let Ok(contents) = std::fs::read_to_string(&file) else {
+            load_error!("Failed to load json file: {}", file.display());
+            return self;
+        };
+        let root = match serde_json::from_str::<serde_json::Value>(&contents) {
+            Ok(root) => root,
+            Err(err) => {
+                load_error!("Failed to parse json file {}: {}", file.display(), err);
+                return self;
+            }
+        };
+        self.locale = self.locale_code(locale);
+        let mut mapping = Vec::new();
+        Self::flatten_json("", &root, &mut mapping);
+        self.data(locales.into_iter(), mapping.into_iter(), true)
+            .load_fallbacks()
+    }
+
+    /// Modifies the current Translations data to load from a TOML file and load a particular locale.
+    /// Each top-level table is a locale, such as `[en]` followed by `hello = "hello"`, and the available locales are collected from those table names.
+    /// Nested tables are flattened using a `.` separator like `json_file`, so `[en.menu]` with `play = "Play"` produces the key `menu.play`.
+    #[cfg(feature = "toml")]
+    pub fn toml_file(&mut self, path: &Path, locale: &String) -> &mut Self {
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            load_error!("Failed to load toml file: {}", path.display());
+            return self;
+        };
+        let mut root = match contents.parse::<toml::Table>() {
+            Ok(root) => root,
+            Err(err) => {
+                load_error!("Failed to parse toml file {}: {}", path.display(), err);
+                return self;
+            }
+        };
+        self.path = TableFile::Toml(path.to_str().unwrap_or_default().into());
+
+        let locales = root
+            .iter()
+            .filter(|(_, value)| value.is_table())
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<String>>();
+        if locales.is_empty() {
+            warn!("Collected empty locale list!");
+        }
+        let pref_code = self.locale_code(locale);
+        let Some(name) = locales
+            .iter()
+            .find(|name| self.locale_code(name.as_str()) == pref_code)
+            .cloned()
+        else {
+            warn!(
+                "Locale {:?} not found in {:?}, found {:?}",
+                locale, path, locales
+            );
+            return self.data(locales.into_iter(), std::iter::empty(), true);
+        };
+        self.locale = pref_code;
+        let mut mapping = Vec::new();
+        if let Some(table) = root.remove(&name) {
+            Self::flatten_toml("", &table, &mut mapping);
+        }
+        self.data(locales.into_iter(), mapping.into_iter(), true)
+            .load_fallbacks()
+    }
+
+    #[cfg(feature = "toml")]
+    fn flatten_toml(prefix: &str, value: &toml::Value, out: &mut Vec<(String, String)>) {
+        match value {
+            toml::Value::Table(table) => {
+                for (key, value) in table {
+                    Self::flatten_toml(&Self::nested_key(prefix, key), value, out);
+                }
+            }
+            toml::Value::String(s) => out.push((prefix.into(), s.clone())),
+            other => out.push((prefix.into(), other.to_string())),
+        }
+    }
+
+    /// Modifies the current Translations data to load from a Rails-style i18n YAML file and load a particular locale.
+    /// Each top-level key is a locale, such as `en: { hello: hello }`, and the available locales are collected from those keys.
+    /// Nested mappings are flattened using a `.` separator like `json_file`, and sequences use their index, so `days: [Mon, Tue]` produces `days.0` and `days.1`.
+    #[cfg(feature = "yaml")]
+    pub fn yaml_file(&mut self, path: &Path, locale: &String) -> &mut Self {
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            load_error!("Failed to load yaml file: {}", path.display());
+            return self;
+        };
+        let root = match serde_yaml::from_str::<serde_yaml::Mapping>(&contents) {
+            Ok(root) => root,
+            Err(err) => {
+                load_error!("Failed to parse yaml file {}: {}", path.display(), err);
+                return self;
+            }
+        };
+        self.path = TableFile::Yaml(path.to_str().unwrap_or_default().into());
+
+        let locales = root
+            .iter()
+            .filter(|(_, value)| value.is_mapping())
+            .filter_map(|(name, _)| Self::yaml_scalar(name))
+            .collect::<Vec<String>>();
+        if locales.is_empty() {
+            warn!("Collected empty locale list!");
+        }
+        let pref_code = self.locale_code(locale);
+        let Some((_, table)) = root.iter().find(|(name, value)| {
+            value.is_mapping()
+                && Self::yaml_scalar(name).is_some_and(|name| self.locale_code(name) == pref_code)
+        }) else {
+            warn!(
+                "Locale {:?} not found in {:?}, found {:?}",
+                locale, path, locales
+            );
+            return self.data(locales.into_iter(), std::iter::empty(), true);
+        };
+        self.locale = pref_code;
+        let mut mapping = Vec::new();
+        Self::flatten_yaml("", table, &mut mapping);
+        self.data(locales.into_iter(), mapping.into_iter(), true)
+            .load_fallbacks()
+    }
+
+    #[cfg(feature = "yaml")]
+    fn flatten_yaml(prefix: &str, value: &serde_yaml::Value, out: &mut Vec<(String, String)>) {
+        match value {
+            serde_yaml::Value::Mapping(map) => {
+                for (key, value) in map {
+                    if let Some(key) = Self::yaml_scalar(key) {
+                        Self::flatten_yaml(&Self::nested_key(prefix, &key), value, out);
+                    }
+                }
+            }
+            serde_yaml::Value::Sequence(items) => {
+                for (index, value) in items.iter().enumerate() {
+                    Self::flatten_yaml(&Self::nested_key(prefix, &index.to_string()), value, out);
+                }
+            }
+            serde_yaml::Value::Tagged(tagged) => Self::flatten_yaml(prefix, &tagged.value, out),
+            scalar => out.extend(Self::yaml_scalar(scalar).map(|s| (prefix.into(), s))),
+        }
+    }
+
+    /// The text of a YAML scalar, with `~` read as an empty value.
+    #[cfg(feature = "yaml")]
+    fn yaml_scalar(value: &serde_yaml::Value) -> Option<String> {
+        match value {
+            serde_yaml::Value::String(s) => Some(s.clone()),
+            serde_yaml::Value::Number(n) => Some(n.to_string()),
+            serde_yaml::Value::Bool(b) => Some(b.to_string()),
+            serde_yaml::Value::Null => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    /// Joins a nested key onto its parent with a `.` separator, used when flattening nested JSON, TOML, and YAML data.
+    #[cfg(any(feature = "json", feature = "toml", feature = "yaml"))]
+    fn nested_key(prefix: &str, key: &str) -> String {
+        if prefix.is_empty() {
+            key.into()
+        } else {
+            format!("{}.{}", prefix, key)
+        }
+    }
+
+    /// Modifies the current Translations data to load from an Android resource directory and load a particular locale.
+    /// Strings are read from `res_dir/values-{locale}/strings.xml`, with Android's region qualifiers mapped to locale codes so `values-pt-rBR` is the `pt-BR` locale.
+    /// The available locales are collected from the `values-*` directories. The unqualified `values` directory has no locale and is not loaded.
+    #[cfg(feature = "android")]
+    pub fn android_strings(&mut self, res_dir: &Path, locale: &String) -> &mut Self {
+        let Some(dirs) = android::scan_locales(res_dir) else {
+            load_error!(
+                "Failed to read android resource directory: {}",
+                res_dir.display()
+            );
+            return self;
+        };
+        self.path = TableFile::Android(res_dir.to_str().unwrap_or_default().into());
+        let locales = dirs
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<String>>();
+        if locales.is_empty() {
+            warn!("Collected empty locale list!");
+        }
+
+        let pref_code = self.locale_code(locale);
+        let Some((_, dir)) = dirs
+            .iter()
+            .find(|(name, _)| self.locale_code(name.as_str()) == pref_code)
+        else {
+            warn!(
+                "Locale {:?} not found in {:?}, found {:?}",
+                locale, res_dir, locales
+            );
+            return self.data(locales.into_iter(), std::iter::empty(), true);
+        };
+        let file = dir.join("strings.xml");
+        let Ok(contents) = std::fs::read_to_string(&file) else {
+            load_error!("Failed to load android strings file: {}", file.display());
+            return self;
+        };
+        let mapping = match android::parse_strings(&contents) {
+            Ok(mapping) => mapping,
+            Err(err) => {
+                load_error!(
+                    "Failed to parse android strings file {}: {}",
+                    file.display(),
+                    err
+                );
+                return self;
+            }
+        };
+        self.locale = pref_code;
+        self.data(locales.into_iter(), mapping.into_iter(), true)
+            .load_fallbacks()
+    }
+
+    /// Modifies the current Translations data to load from a directory of Apple `.lproj` bundles and load a particular locale.
+    /// Strings are read from `lproj_dir/{locale}.lproj/Localizable.strings`, in either UTF-8 or UTF-16, and the available locales are collected from the `*.lproj` directories.
+    #[cfg(feature = "apple")]
+    pub fn apple_strings(&mut self, lproj_dir: &Path, locale: &String) -> &mut Self {
+        let Some(dirs) = apple::scan_locales(lproj_dir) else {
+            load_error!("Failed to read lproj directory: {}", lproj_dir.display());
+            return self;
+        };
+        self.path = TableFile::Apple(lproj_dir.to_str().unwrap_or_default().into());
+        let locales = dirs
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<String>>();
+        if locales.is_empty() {
+            warn!("Collected empty locale list!");
+        }
+
+        let pref_code = self.locale_code(locale);
+        let Some((_, dir)) = dirs
+            .iter()
+            .find(|(name, _)| self.locale_code(name.as_str()) == pref_code)
+        else {
+            warn!(
+                "Locale {:?} not found in {:?}, found {:?}",
+                locale, lproj_dir, locales
+            );
+            return self.data(locales.into_iter(), std::iter::empty(), true);
+        };
+        let file = dir.join("Localizable.strings");
+        let Ok(bytes) = std::fs::read(&file) else {
+            load_error!("Failed to load strings file: {}", file.display());
+            return self;
+        };
+        self.locale = pref_code;
+        let mapping = apple::parse_strings(&apple::decode(&bytes));
+        self.data(locales.into_iter(), mapping.into_iter(), true)
+            .load_fallbacks()
+    }
+
+    /// Collects the sorted locale names of every file in the directory with the given extension, such as `en` from `dir/en.json`.
+    #[cfg(any(feature = "json", feature = "gettext", feature = "fluent"))]
+    fn scan_locale_dir(dir: &Path, extension: &str) -> Option<Vec<String>> {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            load_error!("Failed to read locale directory: {}", dir.display());
+            return None;
+        };
+        let mut locales = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == extension))
+            .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+            .collect::<Vec<String>>();
+        locales.sort();
+        if locales.is_empty() {
+            warn!("Collected empty locale list!");
+        }
+        Some(locales)
+    }
+
+    /// Modifies the current Translations data to load from a gettext `.po` catalog, using each `msgid` as the key.
+    /// The file is named after its locale (`dir/es.po`) and the available locales are collected from the other `.po` files in the same directory, allowing `use_locale` to switch catalogs.
+    /// Fuzzy and untranslated entries are skipped.
+    #[cfg(feature = "gettext")]
+    pub fn po_file(&mut self, path: &Path) -> &mut Self {
+        let dir = path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let Some(locale) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            load_error!("Failed to determine locale of po file: {}", path.display());
+            return self;
+        };
+        let Some(locales) = Self::scan_locale_dir(dir, "po") else {
+            return self;
+        };
+        self.path = TableFile::Po(dir.to_str().unwrap_or_default().into());
+
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            load_error!("Failed to load po file: {}", path.display());
+            return self;
+        };
+        self.locale = self.locale_code(locale);
+        let mapping = gettext::parse_po(&contents);
+        self.data(locales.into_iter(), mapping.into_iter(), true)
+            .load_fallbacks()
+    }
+
+    #[cfg(feature = "json")]
+    fn flatten_json(prefix: &str, value: &serde_json::Value, out: &mut Vec<(String, String)>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, value) in map {
+                    Self::flatten_json(&Self::nested_key(prefix, key), value, out);
+                }
+            }
+            serde_json::Value::String(s) => out.push((prefix.into(), s.clone())),
+            serde_json::Value::Null => out.push((prefix.into(), "".into())),
+            other => out.push((prefix.into(), other.to_string())),
+        }
+    }
+
+    /// Modifies the current Translations data to load from raw data.
+    /// Note that using this method directly does not support changing locales. If you want that feature, use `data_multi` or load from a file such as CSV or ODS.
+    pub fn data<S>(
+        &mut self,
+        locales: impl Iterator<Item = S>,
+        mapping: impl Iterator<Item = (S, S)>,
+        clear_old_data: bool,
+    ) -> &mut Self
+    where
+        S: ToString,
+    {
+        self.identity = false;
+        if clear_old_data {
+            if let Some(cache) = &self.args_cache {
+                cache.clear();
+            }
+            self.available_locales.clear();
+            self.mappings.clear();
+            self.merged.clear();
+            self.columns.clear();
+            self.duplicate_keys.clear();
+            #[cfg(feature = "fluent")]
+            {
+                self.fluent = None;
+            }
+        }
+        let mut available_locales: Vec<LocaleCode> = Vec::new();
+        for code in locales
+            .map(|code| code.to_string().trim().to_string())
+            .filter(|code| !code.is_empty() && !self.is_comment_column(code))
+        {
+            let code = self.locale_code(code);
+            // the first column of a locale is the one loaded, so later columns of the same locale are unreachable
+            if available_locales.contains(&code) {
+                warn!(
+                    "Locale {:?} is defined by more than one column, only the first one is used",
+                    self.locale_string(&code)
+                );
+                continue;
+            }
+            available_locales.push(code);
+        }
+        self.available_locales = available_locales;
+        // avoids rehashing repeatedly while inserting large tables. Streamed CSV rows have no size hint and grow the map as they are read
+        self.mappings.reserve(mapping.size_hint().0);
+        // original spelling of each key inserted by this call, used to report duplicates and case collisions.
+        // When the old data was cleared and keys keep their case, any existing value was inserted by this call, so the keys are not copied.
+        let mut inserted: Option<HashMap<String, String>> =
+            (self.case_insensitive_keys || !clear_old_data).then(HashMap::new);
+        for (key, value) in mapping {
+            let original = format!("{}{}", self.key_prefix, key.to_string().trim());
+            let key = self.normalize_key(&original).into_owned();
+            let previous = match inserted.as_mut() {
+                Some(inserted) => match inserted.get(&key) {
+                    Some(previous) => Some(previous.clone()),
+                    None => {
+                        inserted.insert(key.clone(), original.clone());
+                        None
+                    }
+                },
+                None => self.mappings.contains_key(&key).then(|| original.clone()),
+            };
+            match &previous {
+                Some(previous) if *previous != original => warn!(
+                    "Translation key {:?} collides with {:?} when ignoring case, overwriting its value",
+                    original, previous
+                ),
+                Some(_) => warn!(
+                    "Translation key {:?} is defined more than once, overwriting its value",
+                    original
+                ),
+                None => (),
+            }
+            let value = self.clean_value(value.to_string());
+            if let (Some(clobbered), Some(_)) = (self.mappings.insert(key, value), previous) {
+                self.duplicate_keys.push((original, clobbered));
+            }
+        }
+        self
+    }
+
+    /// The keys defined more than once by the data loaded for the current locale, paired with the value that was overwritten.
+    /// Later rows win, so each entry holds the value of an earlier row. Cleared whenever new data replaces the old, such as on `use_locale`.
+    pub fn duplicate_keys(&self) -> &[(String, String)] {
+        &self.duplicate_keys
+    }
+
+    /// Modifies the current Translations data to load from raw rows holding the value of every locale, such as `("hello", vec![("en", "hello"), ("es", "hola")])`.
+    /// The rows are kept in memory as a `TableFile::Memory` source, so `use_locale` and the fallback chain work without a source file.
+    /// The current locale is kept when the rows have values for it, otherwise the first locale found becomes the current one.
+    pub fn data_multi<S>(&mut self, rows: impl Iterator<Item = (S, Vec<(S, S)>)>) -> &mut Self
+    where
+        S: ToString,
+    {
+        let mut table: Vec<(String, Vec<(String, String)>)> = Vec::new();
+        for (key, values) in rows {
+            let key = key.to_string();
+            for (locale, value) in values {
+                let locale = locale.to_string().trim().to_string();
+                if self.is_comment_column(&locale) {
+                    continue;
+                }
+                let entry = (key.clone(), value.to_string());
+                match table.iter_mut().find(|(name, _)| *name == locale) {
+                    Some((_, column)) => column.push(entry),
+                    None => table.push((locale, vec![entry])),
+                }
+            }
+        }
+        let current = table
+            .iter()
+            .map(|(name, _)| name)
+            .find(|name| self.locale_code(name.as_str()) == self.locale)
+            .or(table.first().map(|(name, _)| name))
+            .cloned()
+            .unwrap_or_default();
+        self.memory_raw(table, &current)
+    }
+
+    /// Modifies the current Translations data to load from a table kept in memory that holds the values of every locale, and load a particular locale.
+    /// Unlike `data`, `use_locale` can switch between the locales of the table.
+    pub fn memory(
+        &mut self,
+        table: HashMap<LocaleCode, HashMap<String, String>>,
+        locale: &String,
+    ) -> &mut Self {
+        let mut table = table
+            .into_iter()
+            .map(|(code, column)| {
+                let mut column = column.into_iter().collect::<Vec<_>>();
+                column.sort();
+                (self.locale_string(&code), column)
+            })
+            .collect::<Vec<_>>();
+        table.sort();
+        self.memory_raw(table, locale)
+    }
+
+    fn memory_raw(
+        &mut self,
+        table: Vec<(String, Vec<(String, String)>)>,
+        locale: &String,
+    ) -> &mut Self {
+        self.memory_column(&table, locale);
+        self.path = TableFile::Memory(table);
+        self.load_fallbacks()
+    }
+
+    /// Loads a locale from rows held in memory without recording them as the source.
+    fn memory_column(&mut self, table: &[(String, Vec<(String, String)>)], locale: &String) {
+        let locales = table
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<String>>();
+        let pref_code = self.locale_code(locale);
+        let mapping = match table
+            .iter()
+            .find(|(name, _)| self.locale_code(name.as_str()) == pref_code)
+        {
+            Some((_, column)) => {
+                self.locale = pref_code;
+                column.clone()
+            }
+            None => {
+                warn!(
+                    "Locale {:?} not found in memory table, found {:?}",
+                    locale, locales
+                );
+                Vec::new()
+            }
+        };
+        self.data(locales.into_iter(), mapping.into_iter(), true);
+    }
+
+    /// Trims surrounding whitespace from a value unless whitespace is preserved.
+    fn clean_value(&self, value: String) -> String {
+        if self.preserve_whitespace {
+            value
+        } else {
+            value.trim().into()
+        }
+    }
+
+    /// Sets the prefix marking header cells of notes or context columns for translators, which are then not treated as locales. Defaults to `#`, as in `#notes`.
+    /// An empty prefix treats every column as a locale.
+    pub fn comment_prefix(&mut self, prefix: impl ToString) -> &mut Self {
+        self.comment_prefix = prefix.to_string();
+        self
+    }
+
+    fn is_comment_column(&self, header: &str) -> bool {
+        !self.comment_prefix.is_empty() && header.starts_with(&self.comment_prefix)
+    }
+
+    /// Sets whether leading and trailing whitespace of values is kept, such as for indented or pre-formatted text. Keys are always trimmed. Disabled by default.
+    /// CSV fields are also trimmed while parsing unless `CsvOptions::trim` is disabled, and the change applies to data loaded afterwards.
+    pub fn preserve_whitespace(&mut self, enabled: bool) -> &mut Self {
+        self.preserve_whitespace = enabled;
+        self
+    }
+
+    /// Replaces translations of the current locale with corrected values, such as fixes submitted by players, taking precedence over the loaded table and any merged sources.
+    /// By default the overrides are remembered per locale and applied again whenever `use_locale` loads that locale, including reloads by the `TranslationWatcher`.
+    /// With `keep_overrides(false)` they are dropped by the next `use_locale` instead. Loading a table directly, such as with `csv_file`, replaces them until the next `use_locale`.
+    pub fn apply_overrides(&mut self, overrides: HashMap<String, String>) -> &mut Self {
+        let mut applied = HashMap::with_capacity(overrides.len());
+        for (key, value) in overrides {
+            let key = self.normalize_key(key.trim()).into_owned();
+            let value = self.clean_value(value);
+            self.mappings.insert(key.clone(), value.clone());
+            applied.insert(key, value);
+        }
+        self.overrides
+            .entry(self.locale.clone())
+            .or_default()
+            .extend(applied);
+        self
+    }
+
+    /// Sets whether overrides from `apply_overrides` are kept when switching locales, so they are applied again whenever their locale is loaded. Enabled by default.
+    pub fn keep_overrides(&mut self, enabled: bool) -> &mut Self {
+        self.keep_overrides = enabled;
+        self
+    }
+
+    /// Forgets every override from `apply_overrides`. Values already applied stay until the current locale is loaded again.
+    pub fn clear_overrides(&mut self) -> &mut Self {
+        self.overrides.clear();
+        self
+    }
+
+    fn reapply_overrides(&mut self) {
+        if let Some(overrides) = self.overrides.get(&self.locale) {
+            self.mappings.extend(
+                overrides
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.clone())),
+            );
+        }
+    }
+
+    /// Inserts or replaces a single translation for the current locale. Key and value are trimmed the same way loaded data is.
+    /// The change only lives in memory, so switching locales or reloading the source discards it.
+    pub fn set_translation(&mut self, key: impl ToString, value: impl ToString) -> &mut Self {
+        let key = self.normalize_key(key.to_string().trim()).into_owned();
+        let value = self.clean_value(value.to_string());
+        self.mappings.insert(key, value);
+        self
+    }
+
+    /// Removes a single translation for the current locale, returning its value if it was loaded.
+    pub fn remove_translation(&mut self, key: impl ToString) -> Option<String> {
+        let key = self.normalize_key(key.to_string().trim()).into_owned();
+        self.mappings.remove(&key)
+    }
+
+    /// Removes every translation of the current locale while keeping the selected locale and source, so `use_locale` can load it again.
+    pub fn clear(&mut self) -> &mut Self {
+        self.mappings.clear();
+        self
+    }
+
+    /// Iterates over every key loaded for the current locale, in no particular order. Keys only present in fallback locales are not included.
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.mappings.keys()
+    }
+
+    /// Iterates over every key and value loaded for the current locale, in no particular order.
+    pub fn entries(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.mappings.iter()
+    }
+
+    /// The number of translations loaded for the current locale.
+    pub fn len(&self) -> usize {
+        self.mappings.len()
+    }
+
+    /// Whether no translations are loaded for the current locale.
+    pub fn is_empty(&self) -> bool {
+        self.mappings.is_empty()
+    }
+
+    /// Sets the column of CSV, ODS, and xlsx tables that holds the keys, starting at 0 for the first column. Defaults to 0.
+    /// The change applies to data loaded afterwards, so call this before loading a table.
+    pub fn key_column(&mut self, index: usize) -> &mut Self {
+        self.key_column = index;
+        self
+    }
+
+    /// Sets the row of ODS sheets that holds the locales, starting at 0 for the first row, so title rows or notes above the table can be skipped. Defaults to 0.
+    /// Unless `ods_data_row` is set, the keys and values start on the row right after it.
+    /// The change applies to data loaded afterwards, so call this before loading a table.
+    #[cfg(feature = "ods")]
+    pub fn ods_header_row(&mut self, index: usize) -> &mut Self {
+        self.ods_header_row = index;
+        self
+    }
+
+    /// Sets the first row of ODS sheets that holds keys and values, starting at 0 for the first row. Defaults to the row after `ods_header_row`.
+    /// The change applies to data loaded afterwards, so call this before loading a table.
+    #[cfg(feature = "ods")]
+    pub fn ods_data_row(&mut self, index: usize) -> &mut Self {
+        self.ods_data_row = Some(index);
+        self
+    }
+
+    /// Sets how the cells of ODS sheets are turned into text, see `OdsCellText`. Defaults to `OdsCellText::Typed`.
+    /// With `OdsCellText::Raw`, percentage and currency cells lose their percent sign and currency symbol, so `50%` reads as `0.5` and `$5` as `5`.
+    /// Enter such values as text cells to keep them exactly as shown. The change applies to data loaded afterwards, so call this before loading a table.
+    #[cfg(feature = "ods")]
+    pub fn ods_cell_text(&mut self, policy: OdsCellText) -> &mut Self {
+        self.ods_cell_text = policy;
+        self
+    }
+
+    /// Sets whether keys are matched regardless of case. When enabled, keys are stored and looked up in lowercase so `Hello` and `hello` refer to the same translation.
+    /// Keys differing only by case are reported as a collision while loading. Disabled by default.
+    /// Enabling this after loading reports the collisions among the loaded keys as well. Of the colliding keys, the value of the key that sorts last is kept.
+    pub fn case_insensitive_keys(&mut self, enabled: bool) -> &mut Self {
+        self.case_insensitive_keys = enabled;
+        if enabled {
+            for (key, previous, clobbered) in Self::lowercase_keys(&mut self.mappings) {
+                warn!(
+                    "Translation key {:?} collides with {:?} when ignoring case, overwriting its value",
+                    key, previous
+                );
+                self.duplicate_keys.push((key, clobbered));
+            }
+            for (code, mapping) in self.fallback_mappings.iter_mut() {
+                for (key, previous, _) in Self::lowercase_keys(mapping) {
+                    warn!(
+                        "Translation key {:?} collides with {:?} in fallback locale {:?} when ignoring case, overwriting its value",
+                        key, previous, code
+                    );
+                }
+            }
+        }
+        self
+    }
+
+    /// Converts the keys of a mapping to lowercase, returning each key that collided with an earlier key of different case, that key, and the value it overwrote.
+    /// Keys are converted in sorted order, so the same key wins a collision however the map happens to be ordered.
+    fn lowercase_keys(mapping: &mut HashMap<String, String>) -> Vec<(String, String, String)> {
+        let mut entries = std::mem::take(mapping).into_iter().collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut originals: HashMap<String, String> = HashMap::with_capacity(entries.len());
+        let mut collisions = Vec::new();
+        for (key, value) in entries {
+            let lowercase = key.to_lowercase();
+            if let Some(clobbered) = mapping.insert(lowercase.clone(), value) {
+                let previous = originals.get(&lowercase).cloned().unwrap_or_default();
+                collisions.push((key.clone(), previous, clobbered));
+            }
+            originals.insert(lowercase, key);
+        }
+        collisions
+    }
+
+    /// Converts a key into the form it is stored as in the mappings.
+    /// With the `normalize` feature, keys are converted to Unicode NFC first, so `café` matches whether its accent was written composed or decomposed.
+    /// The key is borrowed when it is already in that form, so lookups do not allocate.
+    fn normalize_key<'a>(&self, key: &'a str) -> Cow<'a, str> {
+        #[cfg(feature = "normalize")]
+        let key: Cow<str> = if unicode_normalization::is_nfc(key) {
+            Cow::Borrowed(key)
+        } else {
+            Cow::Owned(unicode_normalization::UnicodeNormalization::nfc(key).collect())
+        };
+        #[cfg(not(feature = "normalize"))]
+        let key = Cow::Borrowed(key);
+        if self.case_insensitive_keys {
+            Cow::Owned(key.to_lowercase())
+        } else {
+            key
+        }
+    }
+
+    /// A convenience method for calling `use_locale` with the system's preferred locale.
+    /// Each of the user's preferred languages is tried in order before falling back to the first available locale.
+    #[cfg(feature = "auto")]
+    pub fn use_system_locale(&mut self) -> &mut Self {
+        self.use_system_locale_outcome();
+        self
+    }
+
+    /// Selects the system's preferred locale like `use_system_locale`, returning which locale was chosen and how.
+    #[cfg(feature = "auto")]
+    pub fn use_system_locale_outcome(&mut self) -> SystemLocaleOutcome {
+        let preferences: Vec<LocaleCode> = Self::get_system_languages()
+            .into_iter()
+            .map(LocaleCode::from)
+            .collect();
+        let outcome = self.use_preferred_locale(&preferences);
+        debug!("Selecting system locale: {:?}", outcome);
+        outcome
+    }
+
+    /// Switches to the best match for an ordered list of preferred locales, such as languages chosen in a settings menu or sent by a client: an exact match, then a match by language, then the first available locale.
+    /// Returns which locale was chosen and how, the same as `use_system_locale_outcome` does for the system's preferences.
+    pub fn use_preferred_locale(&mut self, preferences: &[LocaleCode]) -> SystemLocaleOutcome {
+        let outcome = if let Some(code) = self.exact_locale(preferences) {
+            SystemLocaleOutcome::Detected(code)
+        } else if let Some(code) = preferences.iter().find_map(|p| self.resolve_locale(p)) {
+            SystemLocaleOutcome::FellBackToLang(code)
+        } else if let Some(code) = self.available_locales.first() {
+            SystemLocaleOutcome::FellBackToFirst(code.clone())
+        } else {
+            return SystemLocaleOutcome::NoneAvailable;
+        };
+        if let SystemLocaleOutcome::Detected(code)
+        | SystemLocaleOutcome::FellBackToLang(code)
+        | SystemLocaleOutcome::FellBackToFirst(code) = &outcome
+        {
+            self.use_locale(self.locale_string(code));
+        }
+        outcome
+    }
+
+    /// Switches locales like `use_locale` and returns the locale that was active before, so a temporary switch such as a language preview can be undone.
+    /// When the requested locale is not available nothing changes, and the returned locale is still the current one.
+    pub fn replace_locale<S>(&mut self, locale: S) -> LocaleCode
+    where
+        S: ToString + Clone,
+    {
+        let previous = self.locale.clone();
+        self.use_locale(locale);
+        previous
+    }
+
+    /// Change the current locale to the new locale if available. Also loads the new mapping data allowing for translations to be loaded immediately.
+    pub fn use_locale<S>(&mut self, locale: S) -> &mut Self
+    where
+        S: ToString + Clone,
+    {
+        // validate this format has a way to load different locales
+        if self.identity {
+            return self;
+        }
+        if self.path == TableFile::None {
+            warn!("Current data format does not allow loading different translation columns.");
+            return self;
+        }
+
+        // validate the requested locale is available
+        let requested = self.locale_code(locale);
+        let Some(code) = self.resolve_locale(&requested) else {
+            warn!(
+                "Requested locale is not available: requested {:?}",
+                requested
+            );
+            return self;
+        };
+        let previous = self.locale.clone();
+        if !self.keep_overrides {
+            self.overrides.clear();
+        }
+        if self.swap_column(&code) {
+            self.reapply_overrides();
+            return self.record_change(previous);
+        }
+        let locale = self.locale_string(&code);
+        #[cfg(any(feature = "csv", feature = "ods"))]
+        let merged = std::mem::take(&mut self.merged);
+        let path = match &self.path {
+            // in-memory sources are moved out instead of cloned, the loader stores them again
+            #[cfg(feature = "csv")]
+            TableFile::CsvRaw(_) => std::mem::replace(&mut self.path, TableFile::None),
+            #[cfg(feature = "ods")]
+            TableFile::OdsRaw(_) => std::mem::replace(&mut self.path, TableFile::None),
+            TableFile::Memory(_) => std::mem::replace(&mut self.path, TableFile::None),
+            path => path.clone(),
+        };
+
+        self.load_source(path, &locale);
+        #[cfg(any(feature = "csv", feature = "ods"))]
+        self.load_merged(merged, &locale);
+        self.reapply_overrides();
+        self.record_change(previous)
+    }
+
+    /// Loads the given locale from each merged source on top of the current data.
+    #[cfg(any(feature = "csv", feature = "ods"))]
+    fn load_merged(&mut self, merged: Vec<(TableFile, String)>, locale: &String) {
+        for (source, prefix) in merged {
+            match source {
+                #[cfg(feature = "csv")]
+                TableFile::Csv(str_path) => {
+                    self.load_csv_prefixed(Path::new(&str_path), locale, &prefix)
+                }
+                #[cfg(feature = "ods")]
+                TableFile::Ods(str_path) => {
+                    self.load_ods_prefixed(Path::new(&str_path), locale, &prefix)
+                }
+                _ => self,
+            };
+        }
+    }
+
+    /// Loads the given locale from a source, dispatching to the loader of its format.
+    fn load_source(&mut self, path: TableFile, locale: &String) -> &mut Self {
+        // collect the key-value pairs based on the current file format
+        match path {
+            #[cfg(feature = "csv")]
+            TableFile::Csv(str_path) => {
+                let options = self.csv_options.clone();
+                self.csv_file_with_options(Path::new(&str_path), locale, options)
+            }
+
+            #[cfg(feature = "csv")]
+            TableFile::CsvRaw(raw_data) => {
+                let options = self.csv_options.clone();
+                self.csv_raw_with_options(raw_data, locale, options)
+            }
+
+            #[cfg(feature = "ods")]
+            TableFile::Ods(str_path) => {
+                let sheet = self.ods_sheet.clone();
+                self.ods_file_sheet(Path::new(&str_path), locale, sheet)
+            }
+
+            #[cfg(feature = "ods")]
+            TableFile::OdsRaw(bytes) => {
+                let sheet = self.ods_sheet.clone();
+                self.ods_bytes(bytes, locale, sheet)
+            }
+
+            #[cfg(feature = "xlsx")]
+            TableFile::Xlsx(str_path) => self.xlsx_file(Path::new(&str_path), locale),
+
+            #[cfg(feature = "json")]
+            TableFile::Json(str_path) => self.json_file(Path::new(&str_path), locale),
+
+            #[cfg(feature = "toml")]
+            TableFile::Toml(str_path) => self.toml_file(Path::new(&str_path), locale),
+
+            #[cfg(feature = "yaml")]
+            TableFile::Yaml(str_path) => self.yaml_file(Path::new(&str_path), locale),
+
+            #[cfg(feature = "gettext")]
+            TableFile::Po(str_path) => {
+                self.po_file(&Path::new(&str_path).join(format!("{}.po", locale)))
+            }
+
+            #[cfg(feature = "fluent")]
+            TableFile::Fluent(str_path) => self.fluent_file(Path::new(&str_path), locale),
+
+            #[cfg(feature = "android")]
+            TableFile::Android(str_path) => self.android_strings(Path::new(&str_path), locale),
+
+            #[cfg(feature = "apple")]
+            TableFile::Apple(str_path) => self.apple_strings(Path::new(&str_path), locale),
+
+            TableFile::Memory(table) => self.memory_raw(table, locale),
+
+            TableFile::None => self,
+        }
+    }
+
+    /// Loads the given locale from a source without taking it, parsing sources held in memory in place instead of cloning them.
+    /// Used for loading other columns, so sources held in memory are not recorded as the source of this instance.
+    fn load_borrowed(&mut self, path: &TableFile, locale: &String) {
+        match path {
+            #[cfg(feature = "csv")]
+            TableFile::CsvRaw(raw_data) => {
+                let options = self.csv_options.clone();
+                self.csv_str(raw_data, locale, options);
+            }
+            #[cfg(feature = "ods")]
+            TableFile::OdsRaw(bytes) => {
+                if let Some(workbook) = Self::read_ods_bytes(bytes) {
+                    let sheet = self.ods_sheet.clone();
+                    self.ods_workbook(workbook, TableFile::None, locale, sheet);
+                }
+            }
+            TableFile::Memory(table) => {
+                self.memory_column(table, locale);
+            }
+            // sources read from disk only hold their path, which is cheap to clone
+            path => {
+                self.load_source(path.clone(), locale);
+            }
+        }
+    }
+
+    /// Finds the available locale that best matches the requested one. An exact match is preferred, then the language on its own (`en` for `en-GB`),
+    /// and when only a language is requested, its single regional variant (`pt-BR` for `pt`).
+    fn resolve_locale(&self, requested: &LocaleCode) -> Option<LocaleCode> {
+        if let Some(code) = self.available_locales.iter().find(|c| *c == requested) {
+            return Some(code.clone());
+        }
+        let mut variants = self
+            .available_locales
+            .iter()
+            .filter(|c| c.same_language(requested));
+        if !requested.script.is_empty() || !requested.region.is_empty() {
+            return variants
+                .find(|c| c.script.is_empty() && c.region.is_empty())
+                .cloned();
+        }
+        match (variants.next(), variants.next()) {
+            (Some(code), None) => Some(code.clone()),
+            _ => None,
+        }
+    }
+
+    /// Picks the best available locale for an ordered list of preferred locales, such as the languages of an `Accept-Language` header.
+    /// Exact matches are preferred in the given order, then matches by language as in `use_locale`. The result can be passed to `use_locale`.
+    pub fn negotiate_locale(&self, preferences: &[LocaleCode]) -> Option<LocaleCode> {
+        self.exact_locale(preferences).or_else(|| {
+            preferences
+                .iter()
+                .find_map(|preferred| self.resolve_locale(preferred))
+        })
+    }
+
+    /// The first preferred locale that is available as is.
+    fn exact_locale(&self, preferences: &[LocaleCode]) -> Option<LocaleCode> {
+        preferences.iter().find_map(|preferred| {
+            self.available_locales
+                .iter()
+                .find(|code| *code == preferred)
+                .cloned()
+        })
+    }
+
+    /// Remembers the locale that was active before a locale change until `take_pending_change` is called.
+    fn record_change(&mut self, previous: LocaleCode) -> &mut Self {
+        if self.locale != previous && self.pending_change.is_none() {
+            self.pending_change = Some(previous);
+        }
+        self
+    }
+
+    /// Sets whether every locale column is kept in memory, so `use_locale` switches between them without reading the source again.
+    /// Enabling this loads the other columns of the current source right away. Disabled by default, which only keeps the current locale and its fallbacks.
+    /// Switching to the current locale still reads the source again, so reloads such as those of the `TranslationWatcher` pick up changes.
+    pub fn eager(&mut self, enabled: bool) -> &mut Self {
+        self.eager = enabled;
+        if enabled {
+            self.load_fallbacks()
+        } else {
+            self.columns.clear();
+            self
+        }
+    }
+
+    /// Switches to a column kept in memory by eager loading. Returns false when the column has to be loaded from the source instead.
+    fn swap_column(&mut self, code: &LocaleCode) -> bool {
+        if !self.eager {
+            return false;
+        }
+        if *code == self.locale {
+            // switching to the current locale reloads it, such as after the source changed on disk, so every column is read again
+            self.columns.clear();
+            return false;
+        }
+        let Some(column) = self.columns.remove(code) else {
+            return false;
+        };
+        let current = std::mem::replace(&mut self.mappings, column);
+        let previous = std::mem::replace(&mut self.locale, code.clone());
+        self.columns.insert(previous, current);
+        self.fallbacks_from_columns();
+        true
+    }
+
+    /// Copies the fallback chain's columns kept in memory into the fallback mappings.
+    fn fallbacks_from_columns(&mut self) {
+        self.fallback_mappings = self
+            .fallback_chain
+            .iter()
+            .filter(|code| **code != self.locale)
+            .filter_map(|code| Some((code.clone(), self.columns.get(code)?.clone())))
+            .collect();
+    }
+
+    /// Takes the locale that was active before any locale changes made since the last call, if the locale has changed since then.
+    /// This is used to notify the rest of the App about locale changes, such as through the `LocaleChanged` event of the `TranslationPlugin`.
+    pub fn take_pending_change(&mut self) -> Option<LocaleCode> {
+        self.pending_change.take()
+    }
+
+    /// Changes the delimiter used between language and region codes when parsing locales from the loaded data and when formatting them back into strings, such as `_` for `en_US`.
+    /// The default is `LocaleCode::REGION_DELIMITER`. Already loaded locales that could not be split with the previous delimiter are parsed again.
+    pub fn with_region_delimiter(&mut self, delimiter: impl ToString) -> &mut Self {
+        self.region_delimiter = delimiter.to_string();
+        // codes that could not be split with the previous delimiter are parsed again
+        let reparse = |code: &LocaleCode, delimiter: &str| {
+            if code.script.is_empty() && code.region.is_empty() {
+                LocaleCode::parse_with_delimiter(&code.lang, delimiter)
+            } else {
+                code.clone()
+            }
+        };
+        self.locale = reparse(&self.locale, &self.region_delimiter);
+        self.available_locales = self
+            .available_locales
+            .iter()
+            .map(|code| reparse(code, &self.region_delimiter))
+            .collect();
+        self
+    }
+
+    /// Parses a locale code using this instance's region delimiter.
+    pub(crate) fn locale_code(&self, code: impl ToString) -> LocaleCode {
+        LocaleCode::parse_with_delimiter(code, &self.region_delimiter)
+    }
+
+    /// Formats a locale code using this instance's region delimiter.
+    pub(crate) fn locale_string(&self, code: &LocaleCode) -> String {
+        code.to_string_with_delimiter(&self.region_delimiter)
+    }
+
+    /// Returns an optional string if able to acquire the system's current locale code. Basically just a small wrapper around `bevy_device_lang` for convenience
+    #[cfg(feature = "auto")]
+    pub fn get_system_language() -> Option<String> {
+        bevy_device_lang::get_lang()
+    }
+
+    /// Returns the user's preferred languages in order, most preferred first, using `-` between segments like `en-US`.
+    /// `bevy_device_lang` only reports a single language, so on Linux the ordered `LANGUAGE` list used by gettext is read first.
+    #[cfg(feature = "auto")]
+    pub fn get_system_languages() -> Vec<String> {
+        let mut languages: Vec<String> = Vec::new();
+        #[cfg(target_os = "linux")]
+        if let Ok(list) = std::env::var("LANGUAGE") {
+            languages.extend(list.split(':').map(String::from));
+        }
+        languages.extend(Self::get_system_language());
+
+        let mut normalized: Vec<String> = Vec::new();
+        for language in languages {
+            // POSIX locales look like `en_US.UTF-8@euro`
+            let language = language
+                .split(['.', '@'])
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .replace('_', "-");
+            if language.is_empty() || language == "C" || language == "POSIX" {
+                continue;
+            }
+            if !normalized.contains(&language) {
+                normalized.push(language);
+            }
+        }
+        normalized
+    }
+
+    /// Whether the current locale is written right-to-left, for flipping UI layouts. An explicit script decides when present, so `ks-Deva` is left-to-right.
+    /// Otherwise the language is looked up in `RTL_LANGUAGES`, or the list given to `set_rtl_languages`.
+    pub fn is_rtl(&self) -> bool {
+        const RTL_SCRIPTS: [&str; 7] = ["Adlm", "Arab", "Hebr", "Nkoo", "Rohg", "Syrc", "Thaa"];
+        if !self.locale.script.is_empty() {
+            return RTL_SCRIPTS
+                .iter()
+                .any(|script| script.eq_ignore_ascii_case(&self.locale.script));
+        }
+        self.rtl_languages
+            .iter()
+            .any(|lang| lang.eq_ignore_ascii_case(&self.locale.lang))
+    }
+
+    /// Replaces the languages that `is_rtl` treats as right-to-left.
+    pub fn set_rtl_languages(&mut self, langs: &[&str]) -> &mut Self {
+        self.rtl_languages = langs.iter().map(|lang| lang.to_string()).collect();
+        self
+    }
+
+    /// Converts the text to uppercase following the rules of the current language, such as for button labels.
+    /// Turkish and Azerbaijani keep the dot of `i` as `İ`, and Greek drops the accents of stressed vowels. Every other language uses the default Unicode mapping, which already turns German `ß` into `SS`.
+    pub fn to_upper_locale(&self, s: &str) -> String {
+        match self.locale.lang.to_lowercase().as_str() {
+            "tr" | "az" => s.replace('i', "İ").to_uppercase(),
+            "el" => s
+                .to_uppercase()
+                .chars()
+                .map(|c| match c {
+                    'Ά' => 'Α',
+                    'Έ' => 'Ε',
+                    'Ή' => 'Η',
+                    'Ί' => 'Ι',
+                    'Ό' => 'Ο',
+                    'Ύ' => 'Υ',
+                    'Ώ' => 'Ω',
+                    c => c,
+                })
+                .collect(),
+            _ => s.to_uppercase(),
+        }
+    }
+
+    /// Converts the text to lowercase following the rules of the current language.
+    /// Turkish and Azerbaijani lowercase `I` to the dotless `ı` and `İ` to `i`. Every other language uses the default Unicode mapping.
+    pub fn to_lower_locale(&self, s: &str) -> String {
+        match self.locale.lang.to_lowercase().as_str() {
+            "tr" | "az" => s.replace('I', "ı").replace('İ', "i").to_lowercase(),
+            _ => s.to_lowercase(),
+        }
+    }
+
+    /// The character separating the integer and fractional parts of a number in the current locale, such as `,` in `3,5` for German. Defaults to `.` for unknown locales.
+    pub fn decimal_separator(&self) -> char {
+        self.separators().0
+    }
+
+    /// The character separating groups of thousands in the current locale, such as `.` in `1.000` for German or a no-break space for French. Defaults to `,` for unknown locales.
+    pub fn grouping_separator(&self) -> char {
+        self.separators().1
+    }
+
+    /// Looks up the decimal and grouping separators of the current locale in a small table following CLDR, with regional exceptions checked first.
+    fn separators(&self) -> (char, char) {
+        let lang = self.locale.lang.to_lowercase();
+        let region = self.locale.region.to_uppercase();
+        match (lang.as_str(), region.as_str()) {
+            ("de" | "it", "CH") | ("rm", _) => ('.', '’'),
+            ("es", "MX" | "US" | "PR") => ('.', ','),
+            ("pt", "PT") | ("fr", _) => (',', '\u{202F}'),
+            (
+                "de" | "es" | "it" | "pt" | "nl" | "id" | "tr" | "da" | "el" | "ro" | "hr" | "sl"
+                | "sr" | "vi" | "is",
+                _,
+            ) => (',', '.'),
+            (
+                "ru" | "uk" | "be" | "pl" | "cs" | "sk" | "fi" | "sv" | "nb" | "no" | "nn" | "hu"
+                | "bg" | "lt" | "lv" | "et" | "ka" | "kk",
+                _,
+            ) => (',', '\u{A0}'),
+            _ => ('.', ','),
+        }
+    }
+
+    /// Sets the font to use for text in the given locale, such as a CJK font for `ja`. A font set for a language also covers its regional variants.
+    pub fn set_font(&mut self, locale: impl ToString, path: impl Into<PathBuf>) -> &mut Self {
+        self.fonts.insert(self.locale_code(locale), path.into());
+        self
+    }
+
+    /// Returns the font set for the current locale with `set_font`, or the font of its language when the locale itself has none.
+    pub fn current_font(&self) -> Option<&Path> {
+        let language = LocaleCode {
+            lang: self.locale.lang.clone(),
+            ..Default::default()
+        };
+        self.fonts
+            .get(&self.locale)
+            .or_else(|| self.fonts.get(&language))
+            .map(PathBuf::as_path)
+    }
+
+    /// Returns the currently selected locale. If no locale was ever selected this is the default (empty) `LocaleCode`.
+    pub fn current_locale(&self) -> LocaleCode {
+        self.locale.clone()
+    }
+
+    /// Returns the currently selected locale formatted as a string, such as `en-US`.
+    pub fn current_locale_string(&self) -> String {
+        self.locale_string(&self.locale)
+    }
+
+    /// The source the current data was loaded from, which is reloaded when switching locales.
+    pub fn source(&self) -> &TableFile {
+        &self.path
+    }
+
+    /// The file or directory the current data was loaded from, or `None` when it was loaded from memory, such as with `csv_raw` or `data`.
+    pub fn source_path(&self) -> Option<&Path> {
+        self.path.path()
+    }
+
+    /// Returns the locales available from the loaded data. For file sources these are parsed from the header row, leaving out the header of the key column.
+    pub fn available_locales(&self) -> &[LocaleCode] {
+        &self.available_locales
+    }
+
+    /// Returns the available locales formatted as strings, such as `en-US`, which is convenient for displaying a language selection.
+    pub fn available_locale_strings(&self) -> Vec<String> {
+        self.available_locales
+            .iter()
+            .map(|code| self.locale_string(code))
+            .collect()
+    }
+
+    /// Checks whether the locale is one of the available locales, ignoring case, without switching to it.
+    /// Unlike `use_locale`, no fallback by language is done, which makes it suitable for disabling unavailable options in a language selection.
+    pub fn has_locale(&self, locale: impl ToString) -> bool {
+        self.available_locales.contains(&self.locale_code(locale))
+    }
+
+    /// Consumes and clones the instance to make inserting the resource into a bevy App or World a bit easier when using the builder pattern.
+    pub fn build(&self) -> Self {
+        self.clone() // probably not best practice :/
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    // need to escape out of the target directory
+
+    // assets/lang.ods
+    // target/debug/__.rlib
+    #[cfg(feature = "csv")]
+    const FILE_CSV: &str = "assets/lang.csv";
+    #[cfg(feature = "ods")]
+    const FILE_ODS: &str = "assets/lang.ods";
+    #[cfg(feature = "ods")]
+    const FILE_ODS_SHEETS: &str = "assets/lang_sheets.ods";
+    #[cfg(feature = "xlsx")]
+    const FILE_XLSX: &str = "assets/lang.xlsx";
+    #[cfg(feature = "xlsx")]
+    const FILE_XLSX_BLANK_KEY: &str = "assets/lang_blank_key.xlsx";
+    #[cfg(feature = "zip")]
+    const FILE_ZIP: &str = "assets/lang.zip";
+    #[cfg(feature = "json")]
+    const DIR_JSON: &str = "assets/json";
+    #[cfg(feature = "toml")]
+    const FILE_TOML: &str = "assets/lang.toml";
+    #[cfg(feature = "yaml")]
+    const FILE_YAML: &str = "assets/lang.yml";
+    #[cfg(feature = "android")]
+    const DIR_ANDROID: &str = "assets/android";
+    #[cfg(feature = "apple")]
+    const DIR_APPLE: &str = "assets/apple";
+    #[cfg(feature = "gettext")]
+    const DIR_PO: &str = "assets/po";
+    #[cfg(feature = "fluent")]
+    const DIR_FLUENT: &str = "assets/fluent";
+
+    #[test]
+    fn locale_code_lang() {
+        const LOCALE: [&str; 3] = ["en", "es", "fr"];
+        for loc in LOCALE.into_iter() {
+            let code: LocaleCode = loc.into();
+            assert_eq!(code.lang, loc.to_string());
+            assert_eq!(code.region, "".to_string());
+        }
+    }
+    #[test]
+    fn locale_code_lang_region() {
+        const LOCALE: [(&str, &str); 3] = [("en", "AU"), ("es", "CL"), ("fr", "CI")];
+        for (lang, region) in LOCALE.into_iter() {
+            let code: LocaleCode =
+                format!("{}{}{}", lang, LocaleCode::REGION_DELIMITER, region).into();
+            assert_eq!(code.lang, lang.to_string());
+            assert_eq!(code.region, region.to_string());
+        }
+    }
+
+    #[test]
+    fn locale_code_display_from_str() {
+        for text in ["en", "en-US", "zh-Hans-CN", "es-419"] {
+            let code: LocaleCode = text.parse().unwrap();
+            assert_eq!(code.to_string(), text);
+            assert_eq!(format!("{}", code), String::from(code.clone()));
+            assert_eq!(LocaleCode::from(code.to_string()), code);
+        }
+        assert!("".parse::<LocaleCode>().is_err());
+        assert!("en-U S".parse::<LocaleCode>().is_err());
+        assert!("e1-US".parse::<LocaleCode>().is_err());
+
+        let region = std::borrow::Cow::Borrowed("pt-BR");
+        assert_eq!(LocaleCode::from(&region), LocaleCode::from("pt-BR"));
+        assert_eq!(LocaleCode::from(region), LocaleCode::from("pt-BR"));
+        let owned: std::borrow::Cow<str> = std::borrow::Cow::Owned("pt-BR".into());
+        assert_eq!(LocaleCode::from(owned), LocaleCode::from("pt-BR"));
+        assert_eq!(
+            LocaleCode::from(Box::<str>::from("pt-BR")),
+            LocaleCode::from("pt-BR")
+        );
+        assert_eq!(
+            LocaleCode::from(&format_args!("zh-{}", "Hant")).script,
+            "Hant"
+        );
+    }
+
+    #[test]
+    fn locale_code_hash() {
+        let mut map: HashMap<LocaleCode, &str> = HashMap::new();
+        map.insert("en-US".into(), "first");
+        map.insert("EN-us".into(), "second");
+        map.insert("zh-Hans-CN".into(), "third");
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&"en-us".into()), Some(&"second"));
+        assert_eq!(map.get(&"ZH-HANS-cn".into()), Some(&"third"));
+        assert_eq!(map.get(&"zh-CN".into()), None);
+    }
+
+    #[test]
+    fn locale_code_script() {
+        let code: LocaleCode = "zh-Hans-CN".into();
+        assert_eq!(code.lang, "zh");
+        assert_eq!(code.script, "Hans");
+        assert_eq!(code.region, "CN");
+        assert_eq!(String::from(code.clone()), "zh-Hans-CN");
+        assert_eq!(code, "ZH-hans-cn".into());
+        assert_ne!(code, "zh-Hant-CN".into());
+
+        let code: LocaleCode = "zh-Hant".into();
+        assert_eq!(code.script, "Hant");
+        assert_eq!(code.region, "");
+        assert_eq!(String::from(code), "zh-Hant");
+
+        let code: LocaleCode = "es-419".into();
+        assert_eq!(code.script, "");
+        assert_eq!(code.region, "419");
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "csv"))]
+    fn serde_round_trip() {
+        let mut t = Translations::default();
+        t.set_fallback_chain(vec!["en".into()]);
+        t.csv_raw(
+            "key,en,es\nhello,hello,hola\ngreen,green\n".into(),
+            &"es".into(),
+        )
+        .eager(true);
+
+        let saved = serde_json::to_string(&t).unwrap();
+        let mut restored: Translations = serde_json::from_str(&saved).unwrap();
+        assert_eq!(restored, t);
+        for key in ["hello", "green", "invalid"] {
+            assert_eq!(restored.tr(key), t.tr(key));
+        }
+        restored.use_locale("en");
+        assert_eq!(restored.tr("hello"), "hello");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn eager_columns() {
+        let dir = std::env::temp_dir().join(format!("translation-eager-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("lang.csv");
+        std::fs::write(&file, "key,en,es\nhello,hello,hola\ngreen,green\n").unwrap();
+
+        let mut t = Translations::default();
+        t.set_fallback_chain(vec!["en".into()]);
+        t.csv_file(&file, &"en".into()).eager(true);
+        // the source is no longer read once every column is in memory
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        t.use_locale("es");
+        assert_eq!(t.tr("hello"), "hola");
+        assert_eq!(t.tr("green"), "green");
+        assert_eq!(t.missing_in(&"es".into()), Vec::<String>::new());
+        assert_eq!(t.take_pending_change(), Some("en".into()));
+
+        t.set_translation("blue", "azul");
+        t.use_locale("en");
+        assert_eq!(t.tr("hello"), "hello");
+        assert_eq!(t.missing_in(&"es".into()), vec!["green"]);
+        t.use_locale("es");
+        assert_eq!(t.tr("blue"), "azul");
+    }
+
+    #[test]
+    #[cfg(all(feature = "csv", feature = "ods", feature = "xlsx"))]
+    fn eager_columns_match_lazy() {
+        // columns read in one pass hold the same values as columns loaded one at a time
+        let assert_columns = |t: &mut Translations| {
+            let lazy = t
+                .available_locales
+                .iter()
+                .filter(|code| **code != t.locale)
+                .map(|code| (code.clone(), t.load_column(code)))
+                .collect::<HashMap<_, _>>();
+            assert!(!lazy.is_empty());
+            t.eager(true);
+            assert_eq!(t.columns, lazy);
+        };
+
+        let mut t = Translations::default();
+        t.csv_file(Path::new(FILE_CSV), &"en".into())
+            .load_ods_prefixed(Path::new(FILE_ODS), &"en".into(), "ods.");
+        assert_columns(&mut t);
+
+        let mut t = Translations::default();
+        t.ods_file_sheet(
+            Path::new(FILE_ODS_SHEETS),
+            &"en".into(),
+            OdsSheet::AllPrefixed,
+        );
+        assert_columns(&mut t);
+
+        let mut t = Translations::default();
+        t.xlsx_file(Path::new(FILE_XLSX), &"en".into());
+        assert_columns(&mut t);
+
+        let mut t = Translations::default();
+        t.data_multi(
+            [(
+                "hello",
+                vec![("en", "hello"), ("es", "hola"), ("fr", "salut")],
+            )]
+            .into_iter(),
+        );
+        assert_columns(&mut t);
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn eager_reload_current() {
+        let dir = std::env::temp_dir().join(format!("translation-reload-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("lang.csv");
+        std::fs::write(&file, "key,en,es\nhello,hello,hola\n").unwrap();
+
+        let mut t = Translations::default();
+        t.csv_file(&file, &"en".into()).eager(true);
+        std::fs::write(&file, "key,en,es\nhello,howdy,buenas\n").unwrap();
+        t.use_locale("en");
+        assert_eq!(t.tr("hello"), "howdy");
+        t.use_locale("es");
+        assert_eq!(t.tr("hello"), "buenas");
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn key_column() {
+        let mut t = Translations::default();
+        t.key_column(1).csv_raw(
+            "en,key,es\nhi,hello,hola\ngrass,green,verde\n".into(),
+            &"es".into(),
+        );
+        assert_eq!(t.tr("hello"), "hola");
+        t.use_locale("en");
+        assert_eq!(t.tr("green"), "grass");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn region_fallback_matching() {
+        let mut t = Translations::default();
+        t.csv_raw(
+            "key,en,pt-BR,zh-Hans,zh-Hant\nhello,hello,olá,你好,你好\n".into(),
+            &"en".into(),
+        );
+        t.use_locale("en-GB");
+        assert_eq!(t.current_locale_string(), "en");
+        t.use_locale("pt");
+        assert_eq!(t.current_locale_string(), "pt-BR");
+        assert_eq!(t.tr("hello"), "olá");
+        // ambiguous between two variants, so the locale is left unchanged
+        t.use_locale("zh");
+        assert_eq!(t.current_locale_string(), "pt-BR");
+        t.use_locale("pt-PT");
+        assert_eq!(t.current_locale_string(), "pt-BR");
+        assert!(LocaleCode::from("en-GB").same_language(&"EN-us".into()));
+    }
+
+    #[test]
+    #[cfg(all(feature = "auto", feature = "csv", target_os = "linux"))]
+    fn system_languages() {
+        std::env::set_var("LANGUAGE", "de_DE.UTF-8:es:C:es");
+        let languages = Translations::get_system_languages();
+        assert_eq!(languages[..2], ["de-DE", "es"]);
+
+        let mut t = Translations::default();
+        t.csv_file(Path::new(FILE_CSV), &"en".into())
+            .use_system_locale();
+        assert_eq!(t.current_locale_string(), "es");
+        std::env::remove_var("LANGUAGE");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn preferred_locale_outcome() {
+        let mut t = Translations::default();
+        assert_eq!(
+            t.use_preferred_locale(&["en".into()]),
+            SystemLocaleOutcome::NoneAvailable
+        );
+        t.csv_file(Path::new(FILE_CSV), &"en".into());
+        assert_eq!(
+            t.use_preferred_locale(&["de".into(), "es".into()]),
+            SystemLocaleOutcome::Detected("es".into())
+        );
+        assert_eq!(
+            t.use_preferred_locale(&["en-GB".into()]),
+            SystemLocaleOutcome::FellBackToLang("en".into())
+        );
+        assert_eq!(t.current_locale_string(), "en");
+        assert_eq!(
+            t.use_preferred_locale(&["de".into()]),
+            SystemLocaleOutcome::FellBackToFirst("en".into())
+        );
+
+        // the key column is skipped wherever it is placed
+        let mut t = Translations::default();
+        t.key_column(1)
+            .csv_raw("fr,key,es\nhello,hello,hola\n".into(), &"es".into());
+        assert_eq!(
+            t.use_preferred_locale(&["key".into(), "de".into()]),
+            SystemLocaleOutcome::FellBackToFirst("fr".into())
+        );
+        assert_eq!(t.current_locale_string(), "fr");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn apply_overrides() {
+        let mut t = Translations::default();
+        t.csv_raw(
+            "key,en,es\nhello,hello,hola\ngreen,green,verde\n".into(),
+            &"es".into(),
+        );
+        t.apply_overrides(HashMap::from([("hello".to_string(), "buenas".to_string())]));
+        assert_eq!(t.tr("hello"), "buenas");
+        t.use_locale("en");
+        assert_eq!(t.tr("hello"), "hello");
+        t.use_locale("es");
+        assert_eq!(t.tr("hello"), "buenas");
+        assert_eq!(t.tr("green"), "verde");
+
+        t.keep_overrides(false).use_locale("en").use_locale("es");
+        assert_eq!(t.tr("hello"), "hola");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn replace_locale() {
+        let mut t = Translations::default();
+        t.csv_raw("key,en,es\nhello,hello,hola\n".into(), &"en".into());
+        let previous = t.replace_locale("es");
+        assert_eq!(previous, LocaleCode::from("en"));
+        assert_eq!(t.tr("hello"), "hola");
+        t.use_locale(previous.to_string());
+        assert_eq!(t.tr("hello"), "hello");
+        assert_eq!(t.replace_locale("de"), LocaleCode::from("en"));
+        assert_eq!(t.current_locale_string(), "en");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn right_to_left() {
+        let mut t = Translations::default();
+        t.csv_raw(
+            "key,en,ar-EG,ks-Deva,he\nhello,hello,مرحبا,नमस्कार,שלום\n".into(),
+            &"en".into(),
+        );
+        assert!(!t.is_rtl());
+        t.use_locale("ar-EG");
+        assert!(t.is_rtl());
+        t.use_locale("ks-Deva");
+        assert!(!t.is_rtl());
+        t.use_locale("he");
+        assert!(t.is_rtl());
+        t.set_rtl_languages(&["ar"]);
+        assert!(!t.is_rtl());
+    }
+
+    #[test]
+    fn locale_casing() {
+        let mut t = Translations {
+            locale: "de".into(),
+            ..Default::default()
+        };
+        assert_eq!(t.to_upper_locale("istanbul"), "ISTANBUL");
+        assert_eq!(t.to_upper_locale("straße"), "STRASSE");
+        t.locale = "tr".into();
+        assert_eq!(t.to_upper_locale("istanbul"), "İSTANBUL");
+        assert_eq!(t.to_lower_locale("IŞIK İzmir"), "ışık izmir");
+        t.locale = "el".into();
+        assert_eq!(t.to_upper_locale("καλημέρα"), "ΚΑΛΗΜΕΡΑ");
+    }
+
+    #[test]
+    fn number_separators() {
+        let mut t = Translations {
+            locale: "en-US".into(),
+            ..Default::default()
+        };
+        assert_eq!((t.decimal_separator(), t.grouping_separator()), ('.', ','));
+        t.locale = "de".into();
+        assert_eq!((t.decimal_separator(), t.grouping_separator()), (',', '.'));
+        t.locale = "de-CH".into();
+        assert_eq!((t.decimal_separator(), t.grouping_separator()), ('.', '’'));
+        t.locale = "fr-FR".into();
+        assert_eq!(t.grouping_separator(), '\u{202F}');
+        t.locale = "es-MX".into();
+        assert_eq!(t.decimal_separator(), '.');
+        t.locale = "tlh".into();
+        assert_eq!((t.decimal_separator(), t.grouping_separator()), ('.', ','));
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn locale_fonts() {
+        let mut t = Translations::default();
+        t.csv_raw(
+            "key,en,zh-Hans,zh-Hant,ar\nhello,hello,你好,你好,مرحبا\n".into(),
+            &"en".into(),
+        );
+        t.set_font("zh", "fonts/NotoSansSC.ttf")
+            .set_font("zh-Hant", "fonts/NotoSansTC.ttf");
+        assert_eq!(t.current_font(), None);
+        t.use_locale("zh-Hans");
+        assert_eq!(t.current_font(), Some(Path::new("fonts/NotoSansSC.ttf")));
+        t.use_locale("zh-Hant");
+        assert_eq!(t.current_font(), Some(Path::new("fonts/NotoSansTC.ttf")));
+        t.use_locale("ar");
+        assert_eq!(t.current_font(), None);
+
+        // codes are parsed with the instance's region delimiter
+        let mut t = Translations::default();
+        t.with_region_delimiter('_')
+            .csv_raw("key,en,zh_Hant\nhello,hello,你好\n".into(), &"en".into())
+            .set_font("zh_Hant", "fonts/NotoSansTC.ttf")
+            .use_locale("zh_Hant");
+        assert_eq!(t.current_font(), Some(Path::new("fonts/NotoSansTC.ttf")));
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn negotiate_locale() {
+        let mut t = Translations::default();
+        t.csv_raw(
+            "key,en,en-GB,fr,pt-BR\nhello,hello,hello,bonjour,olá\n".into(),
+            &"en".into(),
+        );
+        let negotiate = |preferences: &[&str]| {
+            let preferences: Vec<LocaleCode> = preferences.iter().map(|p| (*p).into()).collect();
+            t.negotiate_locale(&preferences).map(String::from)
+        };
+        assert_eq!(negotiate(&["en-GB", "fr"]), Some("en-GB".into()));
+        assert_eq!(negotiate(&["fr-CA", "en-GB"]), Some("en-GB".into()));
+        assert_eq!(negotiate(&["fr-CA", "de"]), Some("fr".into()));
+        assert_eq!(negotiate(&["pt"]), Some("pt-BR".into()));
+        assert_eq!(negotiate(&["de", "ja"]), None);
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn has_locale() {
+        let mut t = Translations::default();
+        t.csv_raw("key,en,pt-BR\nhello,hello,olá\n".into(), &"en".into());
+        assert!(t.has_locale("en"));
+        assert!(t.has_locale("PT-br"));
+        assert!(!t.has_locale("pt"));
+        assert!(!t.has_locale("de"));
+        assert!(!t.has_locale("key"));
+        assert_eq!(t.current_locale_string(), "en");
+
+        // codes are parsed with the instance's region delimiter
+        let mut t = Translations::default();
+        t.with_region_delimiter('_')
+            .csv_raw("key,en_US,pt_BR\nhello,hello,olá\n".into(), &"en_US".into());
+        assert!(t.has_locale("pt_BR"));
+        assert!(t.has_locale("en_us"));
+        assert!(!t.has_locale("pt"));
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn comment_columns() {
+        const CSV_DATA_RAW: &str =
+            "key,en,#notes,es\nhello,hello,a greeting,hola\ngreen,green,the color,verde\n";
+        let mut t = Translations::default();
+        t.csv_raw(CSV_DATA_RAW.into(), &"es".into());
+        assert_eq!(t.available_locale_strings(), vec!["en", "es"]);
+        assert_eq!(t.tr("green"), "verde");
+        t.use_locale("#notes");
+        assert_eq!(t.current_locale_string(), "es");
+
+        t.comment_prefix("notes:").csv_raw(
+            CSV_DATA_RAW.replace("#notes", "notes: context"),
+            &"en".into(),
+        );
+        assert_eq!(t.available_locale_strings(), vec!["en", "es"]);
+        t.comment_prefix("")
+            .csv_raw(CSV_DATA_RAW.into(), &"en".into());
+        assert_eq!(t.available_locale_strings().len(), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn preserve_whitespace() {
+        const CSV_DATA_RAW: &str = "key,en\n help ,  --help   show this text\n";
+        let mut t = Translations::default();
+        t.csv_raw(CSV_DATA_RAW.into(), &"en".into());
+        assert_eq!(t.tr("help"), "--help   show this text");
+
+        t.preserve_whitespace(true).csv_raw_with_options(
+            CSV_DATA_RAW.into(),
+            &"en".into(),
+            CsvOptions {
+                trim: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!(t.tr("help"), "  --help   show this text");
+        t.set_translation("indent", "\tnested ");
+        assert_eq!(t.tr("indent"), "\tnested ");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn csv_raw_locale_not_found() {
+        let mut t = Translations::default();
+        t.csv_raw("key,en,es\nhello,hello,hola\n".into(), &"de".into());
+        assert!(t.is_empty());
+        assert_eq!(t.tr_opt("hello"), None);
+        t.use_locale("es");
+        assert_eq!(t.tr("hello"), "hola");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn csv_raw_headers_only() {
+        let mut t = Translations::default();
+        t.csv_raw("key,en,es\nhello,hello,hola\n".into(), &String::new());
+        assert!(t.is_empty());
+        assert!(t.has_locale("en") && t.has_locale("es"));
+        t.use_locale("es");
+        assert_eq!(t.tr("hello"), "hola");
+    }
+
+    #[test]
+    #[cfg(feature = "ods")]
+    fn ods_locale_not_found() {
+        let mut t = Translations::default();
+        t.ods_file(Path::new(FILE_ODS), &"de".into());
+        assert!(t.is_empty());
+        assert_eq!(t.tr("hello"), "hello");
+    }
+
+    #[test]
+    fn message_references() {
+        let mut t = Translations::default();
+        t.data(
+            ["en"].into_iter(),
+            [
+                ("game.title", "Squiggle Quest"),
+                ("studio", "{$game.title} Studios"),
+                (
+                    "greeting",
+                    "Hello {name}, welcome to {$game.title} by {$studio}",
+                ),
+                ("escaped", "{{$game.title}"),
+                ("missing", "{$nope}"),
+                ("ping", "ping {$pong}"),
+                ("pong", "pong {$ping}"),
+            ]
+            .into_iter(),
+            true,
+        );
+        let args = HashMap::from([("name".to_string(), "Ann".to_string())]);
+        assert_eq!(
+            t.tr_args("greeting", &args),
+            "Hello Ann, welcome to Squiggle Quest by Squiggle Quest Studios"
+        );
+        assert_eq!(t.tr_args("escaped", &args), "{$game.title}");
+        assert_eq!(t.tr("missing"), "{$nope}");
+        assert_eq!(t.tr("ping"), "ping pong {$ping}");
+    }
+
+    #[test]
+    fn tr_borrowed_keys() {
+        let mut t = Translations::default();
+        t.data(["en"].into_iter(), [("hello", "howdy")].into_iter(), true);
+        let key = String::from("hello");
+        assert_eq!(t.tr(&key), "howdy");
+        assert_eq!(t.tr_opt(&key), Some("howdy".into()));
+        assert!(t.contains_key(&key));
+        assert_eq!(t.tr(key), "howdy");
+        assert_eq!(t.tr_or("missing", "default"), "default");
+        assert_eq!(t.tr("missing"), "missing");
+        t.case_insensitive_keys(true);
+        assert_eq!(t.tr("HELLO"), "howdy");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn cached_tr_args() {
+        let mut t = Translations::default();
+        t.with_cache(2).csv_raw(
+            "key,en,es\nlevel,Level {n},Nivel {n}\n".into(),
+            &"en".into(),
+        );
+        let args = HashMap::from([("n".to_string(), "3".to_string())]);
+        assert_eq!(t.tr_args("level", &args), "Level 3");
+        assert_eq!(t.tr_args("level", &args), "Level 3");
+        let other = HashMap::from([("n".to_string(), "4".to_string())]);
+        assert_eq!(t.tr_args("level", &other), "Level 4");
+
+        t.use_locale("es");
+        assert_eq!(t.tr_args("level", &args), "Nivel 3");
+        t.set_translation("level", "Piso {n}");
+        assert_eq!(t.tr_args("level", &args), "Piso 3");
+        assert_eq!(t.tr_args("missing {n}", &args), "missing 3");
+    }
+
+    #[test]
+    fn identity() {
+        let mut t = Translations::identity();
+        t.track_missing_keys(true);
+        assert!(t.is_identity());
+        assert_eq!(t.tr("hello"), "hello");
+        assert!(t.available_locales().is_empty());
+        assert_eq!(t.current_locale_string(), "und");
+        t.use_locale("es");
+        assert_eq!(t.current_locale_string(), "und");
+        assert!(t.take_missing_keys().is_empty());
+
+        t.data(["en"].into_iter(), [("hello", "howdy")].into_iter(), true);
+        assert!(!t.is_identity());
+        assert_eq!(t.tr("hello"), "howdy");
+    }
+
+    #[test]
+    fn runtime_edits() {
+        let mut t = Translations::default();
+        t.data(
+            ["en"].into_iter(),
+            [("hello", "hello"), ("green", "green")].into_iter(),
+            true,
+        );
+        t.set_translation(" hello ", " howdy ")
+            .set_translation("sword", "sword");
+        assert_eq!(t.tr("hello"), "howdy");
+        assert_eq!(t.tr("sword"), "sword");
+        assert_eq!(t.remove_translation("green "), Some("green".into()));
+        assert_eq!(t.remove_translation("green"), None);
+        assert_eq!(t.tr_opt("green"), None);
+
+        let mut keys: Vec<&String> = t.keys().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["hello", "sword"]);
+        assert!(t.entries().any(|(k, v)| k == "hello" && v == "howdy"));
+        assert_eq!(t.len(), 2);
+
+        t.clear();
+        assert_eq!(t.tr_opt("hello"), None);
+        assert!(t.is_empty());
+        assert_eq!(t.available_locale_strings(), vec!["en"]);
+    }
+
+    #[test]
+    fn missing_key_tracking() {
+        let mut t = Translations::default();
+        t.data(["en"].into_iter(), [("hello", "hello")].into_iter(), true);
+        t.tr("untracked");
+        t.track_missing_keys(true);
+        t.tr("hello");
+        t.tr("sword");
+        t.tr("sword");
+        t.tr_or("shield", "shield");
+        assert_eq!(
+            t.tr_many(["hello", "bow", "sword"]),
+            vec!["hello", "bow", "sword"]
+        );
+        assert!(t.tr_opt("probe").is_none());
+        assert_eq!(
+            t.take_missing_keys(),
+            vec![
+                ("bow".to_string(), t.current_locale()),
+                ("shield".to_string(), t.current_locale()),
+                ("sword".to_string(), t.current_locale())
+            ]
+        );
+        assert!(t.take_missing_keys().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn validate_coverage() {
+        let mut t = Translations::default();
+        t.csv_file(Path::new(FILE_CSV), &"en".into());
+        assert!(t.validate_coverage().is_empty());
+
+        let dir = std::env::temp_dir().join(format!("translation-coverage-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("gaps.csv");
+        std::fs::write(
+            &file,
+            "key,en,es\nhello,hello,hola\ngreen,green\nblue,blue,\n",
+        )
+        .unwrap();
+        t.csv_file(&file, &"en".into());
+        assert_eq!(
+            t.validate_coverage(),
+            vec![
+                ("es".into(), "blue".to_string()),
+                ("es".into(), "green".to_string())
+            ]
+        );
+        assert_eq!(t.missing_in(&"es".into()), vec!["blue", "green"]);
+        assert_eq!(t.missing_in(&"fr".into()), vec!["blue", "green", "hello"]);
+        t.use_locale("es");
+        assert!(t.missing_in(&"en".into()).is_empty());
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn region_delimiter() {
+        const CSV_DATA_RAW: &str = r#"key, en_US, es_CL
+hello, hello, hola"#;
+
+        let mut t = Translations::default();
+        t.with_region_delimiter('_')
+            .csv_raw(CSV_DATA_RAW.into(), &"en_US".into())
+            .use_locale("es_CL");
+        assert_eq!(t.tr("hello"), "hola");
+        assert_eq!(t.current_locale(), "es-CL".into());
+        assert_eq!(t.current_locale_string(), "es_CL");
+
+        t.with_region_delimiter('-');
+        assert_eq!(t.available_locale_strings(), vec!["en-US", "es-CL"]);
+
+        // setting the delimiter after loading still splits the loaded codes
+        let mut t = Translations::default();
+        t.csv_raw(CSV_DATA_RAW.into(), &"en_US".into())
+            .with_region_delimiter('_');
+        assert_eq!(t.current_locale(), "en-US".into());
+        assert_eq!(t.available_locale_strings(), vec!["en_US", "es_CL"]);
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn load_csv_file() {
+        if let Ok(pwd) = std::env::current_dir() {
+            eprintln!("PWD ==> {}", pwd.display());
+        }
+        let mut t = Translations::default();
+        t.csv_file(Path::new(FILE_CSV), &"en".into());
+        validate_translation_data(&mut t);
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn debug_summary() {
+        let data = (0..100).fold(String::from("key,en\n"), |data, i| {
+            data + &format!("key{},value{}\n", i, i)
+        });
+        let mut t = Translations::default();
+        t.csv_raw(data.clone(), &"en".into());
+        let debug = format!("{:?}", t);
+        let size = format!("path: CsvRaw(<{} bytes>)", data.len());
+        assert!(debug.contains(&size), "{}", debug);
+        assert!(debug.contains("mappings: <100 entries>"), "{}", debug);
+        assert!(!debug.contains("value1"), "{}", debug);
+        assert_eq!(
+            format!("{:?}", TableFile::Csv("lang.csv".into())),
+            "Csv(\"lang.csv\")"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn source_path() {
+        let mut t = Translations::default();
+        assert_eq!(t.source(), &TableFile::None);
+        t.csv_file(Path::new(FILE_CSV), &"en".into());
+        assert_eq!(t.source(), &TableFile::Csv(FILE_CSV.into()));
+        assert_eq!(t.source_path(), Some(Path::new(FILE_CSV)));
+        t.csv_raw("key,en\nhello,hello\n".into(), &"en".into());
+        assert!(matches!(t.source(), TableFile::CsvRaw(_)));
+        assert_eq!(t.source_path(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn try_load_csv_file_errors() {
+        let mut t = Translations::default();
+        assert!(matches!(
+            t.try_csv_file(Path::new("assets/missing.csv"), &"en".into()),
+            Err(TranslationError::FileNotFound(_))
+        ));
+        assert!(matches!(
+            t.try_csv_file(Path::new(FILE_CSV), &"de".into()),
+            Err(TranslationError::LocaleNotFound(_))
+        ));
+        // the file was still read, so switching to a valid locale works
+        validate_translation_data(&mut t);
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn load_csv_with_options() {
+        const CSV_DATA_RAW: &str = "key;en;es\nhello;hello;hola\ngreen;green;verde\nlist;\"a; \"\"b\"\"\";\"a; \"\"b\"\"\"";
+
+        let options = CsvOptions {
+            delimiter: b';',
+            ..CsvOptions::excel()
+        };
+        let mut t = Translations::default();
+        t.csv_raw_with_options(CSV_DATA_RAW.into(), &"en".into(), options);
+        assert_eq!(t.tr("list"), "a; \"b\"");
+        validate_translation_data(&mut t);
+        assert_eq!(t.tr("list"), "a; \"b\"");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn duplicate_header_locales() {
+        const CSV_DATA_RAW: &str = "key,en, en ,ES\nhello,hello,howdy,hola\n";
+
+        let options = CsvOptions {
+            trim: false,
+            ..Default::default()
+        };
+        let mut t = Translations::default();
+        t.csv_raw_with_options(CSV_DATA_RAW.into(), &" en".into(), options);
+        assert_eq!(t.available_locale_strings(), ["en", "ES"]);
+        assert_eq!(t.tr("hello"), "hello");
+        t.use_locale("es");
+        assert_eq!(t.tr("hello"), "hola");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn csv_comment_rows() {
+        const CSV_DATA_RAW: &str =
+            "key,en,es\n# this section is UI strings\nhello,hello,hola\n#green,green,verde\ngreen,green,verde\n";
+
+        let options = CsvOptions {
+            comment: Some(b'#'),
+            ..Default::default()
+        };
+        let mut t = Translations::default();
+        t.csv_raw_with_options(CSV_DATA_RAW.into(), &"en".into(), options);
+        assert_eq!(t.len(), 2);
+        validate_translation_data(&mut t);
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn load_headerless_csv() {
+        const CSV_DATA_RAW: &str = "hello,hello,hola\ngreen,green,verde\n";
+
+        let mut t = Translations::default();
+        t.csv_raw_with_options(
+            CSV_DATA_RAW.into(),
+            &"en".into(),
+            CsvOptions::headerless(["en", "es"]),
+        );
+        assert_eq!(t.available_locale_strings(), ["en", "es"]);
+        validate_translation_data(&mut t);
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn report_short_rows() {
+        const CSV_DATA_RAW: &str = "key,en,es\nhello,hello,hola\ngreen,green\nblue\n";
+
+        let options = CsvOptions {
+            report_short_rows: true,
+            ..Default::default()
+        };
+        let mut t = Translations::default();
+        t.csv_raw_with_options(CSV_DATA_RAW.into(), &"es".into(), options);
+        assert_eq!(t.short_rows(), &[3, 4]);
+        t.use_locale("en");
+        assert_eq!(t.short_rows(), &[4]);
+
+        let mut t = Translations::default();
+        t.csv_raw(CSV_DATA_RAW.into(), &"es".into());
+        assert!(t.short_rows().is_empty());
+    }
+
+    #[test]
+    #[cfg(all(feature = "csv", feature = "ods"))]
+    fn merge_sources() {
+        let dir = std::env::temp_dir().join(format!("translation-merge-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dlc = dir.join("dlc.csv");
+        std::fs::write(
+            &dlc,
+            "key, en, es, fr\ngreen, lime, lima, citron\nsword, sword, espada, épée",
+        )
+        .unwrap();
+
+        let mut t = Translations::default();
+        t.csv_file(Path::new(FILE_CSV), &"es".into())
+            .merge_csv(&dlc, &"es".into());
+        assert_eq!(t.tr("hello"), "hola");
+        assert_eq!(t.tr("green"), "lima");
+        assert_eq!(t.tr("sword"), "espada");
+        assert_eq!(t.available_locale_strings(), vec!["en", "es", "fr"]);
+
+        t.use_locale("en");
+        assert_eq!(t.tr("hello"), "hello");
+        assert_eq!(t.tr("sword"), "sword");
+
+        t.merge_ods(Path::new(FILE_ODS), &"en".into());
+        assert_eq!(t.tr("green"), "green");
+
+        // loading a new primary source drops the merged ones
+        t.csv_file(Path::new(FILE_CSV), &"en".into());
+        assert_eq!(t.tr("sword"), "sword");
+        assert_eq!(t.tr_opt("sword"), None);
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn prefixed_sources() {
+        let dir = std::env::temp_dir().join(format!("translation-prefix-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let ui = dir.join("ui.csv");
+        std::fs::write(&ui, "key,en,es\nhello,hi there,hola amigo\nplay,play").unwrap();
+
+        let mut t = Translations::default();
+        t.set_fallback_chain(vec!["en".into()]);
+        t.csv_file(Path::new(FILE_CSV), &"en".into())
+            .load_csv_prefixed(&ui, &"en".into(), "ui.")
+            .load_csv_prefixed(Path::new(FILE_CSV), &"en".into(), "items.");
+        assert_eq!(t.tr("hello"), "hello");
+        assert_eq!(t.tr("ui.hello"), "hi there");
+        assert_eq!(t.tr("items.green"), "green");
+        assert_eq!(t.tr_opt("play"), None);
+
+        t.use_locale("es");
+        assert_eq!(t.tr("hello"), "hola");
+        assert_eq!(t.tr("ui.hello"), "hola amigo");
+        assert_eq!(t.tr("items.green"), "verde");
+        // missing in es, resolved through the prefixed fallback column
+        assert_eq!(t.tr("ui.play"), "play");
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn load_csv_with_bom() {
+        const CSV_DATA_RAW: &str = "\u{FEFF}en, key, es\nhello, hello, hola";
+
+        let mut t = Translations::default();
+        t.key_column(1).csv_raw(CSV_DATA_RAW.into(), &"es".into());
+        assert_eq!(t.available_locale_strings(), vec!["en", "es"]);
+        assert_eq!(t.tr("hello"), "hola");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    pub fn load_csv_raw() {
+        const CSV_DATA_RAW: &str = r#"key, en, es
+hello, hello, hola,
+green, green, verde"#;
+
+        let mut t = Translations::default();
+        t.csv_raw(CSV_DATA_RAW.into(), &"en".into());
+        validate_translation_data(&mut t);
+    }
+    #[test]
+    #[cfg(feature = "csv")]
+    pub fn load_embedded_csv() {
+        let mut t = Translations::from_embedded_csv(include_str!("../assets/lang.csv"), "es");
+        assert_eq!(t.tr("hello"), "hola");
+        validate_translation_data(&mut t);
+    }
+    #[test]
+    #[cfg(feature = "ods")]
+    fn load_ods() {
+        let mut t = Translations::default();
+        t.ods_file(Path::new(FILE_ODS), &"en".into());
+        validate_translation_data(&mut t);
+    }
+
+    #[test]
+    #[cfg(feature = "ods")]
+    fn load_ods_from_reader() {
+        let file = std::fs::File::open(FILE_ODS).unwrap();
+        let mut t = Translations::default();
+        t.ods_from_reader(std::io::BufReader::new(file), &"en".into());
+        validate_translation_data(&mut t);
+    }
+
+    #[test]
+    #[cfg(feature = "ods")]
+    fn load_ods_raw() {
+        let mut t = Translations::default();
+        t.ods_raw(std::fs::read(FILE_ODS).unwrap(), &"en".into());
+        assert!(matches!(t.source(), TableFile::OdsRaw(_)));
+        validate_translation_data(&mut t);
+
+        let mut t = Translations::from_embedded_ods(include_bytes!("../assets/lang.ods"), "en");
+        validate_translation_data(&mut t);
     }
 
-    /// Modifies the current Translations data to load from a raw string in CSV format and load a particular locale.
-    #[cfg(feature = "csv")]
-    pub fn csv_raw(&mut self, csv_data: String, locale: &String) -> &mut Self {
-        let mut reader = csv::ReaderBuilder::new()
-            .double_quote(false)
-            .escape(Some(b'\\'))
-            .flexible(true)
-            .has_headers(true)
-            .from_reader(csv_data.as_bytes());
-        self.path = TableFile::CsvRaw(csv_data.clone());
+    #[test]
+    #[cfg(feature = "ods")]
+    fn ods_raw_columns() {
+        let mut t = Translations::default();
+        t.set_fallback_chain(vec!["en".into()])
+            .ods_raw(std::fs::read(FILE_ODS).unwrap(), &"es".into())
+            .eager(true);
+        assert_eq!(t.tr("hello"), "hola");
+        assert_eq!(t.missing_in(&"en".into()), Vec::<String>::new());
+        // loading the other columns leaves the recorded source in place
+        assert!(matches!(t.source(), TableFile::OdsRaw(_)));
+        t.use_locale("en");
+        assert_eq!(t.tr("hello"), "hello");
+        t.use_locale("es");
+        assert_eq!(t.tr("green"), "verde");
+    }
 
-        let Ok(head) = reader.headers() else {
-            eprintln!("Failed to collect header row from reader");
-            return self;
-        };
-        let locales = head
-            .into_iter()
-            .map(|s| s.to_string().trim().into())
-            .collect::<Vec<String>>();
-        if locales.is_empty() {
-            eprintln!("Collected empty locale list!");
-        }
+    #[test]
+    #[cfg(feature = "ods")]
+    fn ods_raw_cell_text() {
+        use spreadsheet_ods::{Sheet, Value, WorkBook};
 
-        let locale_index = locales.iter().position(|p| p == locale).unwrap_or_default();
-        if locale_index == 0 {
-            eprintln!(
-                "Locale index not found for locale {:?} in set {:#?}",
-                locale, locales
-            )
-        }
-        self.locale = locale.into();
+        let mut sheet = Sheet::new("lang");
+        sheet.set_value(0, 0, "key");
+        sheet.set_value(0, 1, "en");
+        sheet.set_value(1, 0, "discount");
+        sheet.set_value(1, 1, Value::Percentage(0.5));
+        sheet.set_value(2, 0, "price");
+        sheet.set_value(2, 1, Value::Currency(5., "$".into()));
+        sheet.set_value(3, 0, "version");
+        sheet.set_value(3, 1, "1.2");
+        let mut book = WorkBook::new_empty();
+        book.push_sheet(sheet);
+        let bytes = spreadsheet_ods::write_ods_buf(&mut book, Vec::new()).unwrap();
 
-        let mapping = reader
-            .records()
-            .map(|p| {
-                let rec = p.unwrap_or_default();
-                (
-                    rec.get(0).unwrap_or_default().to_string(),
-                    rec.get(locale_index).unwrap_or_default().to_string(),
-                )
-            })
-            .collect::<Vec<(String, String)>>();
-        self.data(locales.into_iter(), mapping.into_iter(), true)
+        let mut t = Translations::default();
+        t.ods_bytes(bytes.clone(), &"en".into(), 0);
+        assert_eq!(t.tr("discount"), "50%");
+        assert_eq!(t.tr("price"), "$5");
+
+        let mut t = Translations::default();
+        t.ods_cell_text(OdsCellText::Raw)
+            .ods_bytes(bytes, &"en".into(), 0);
+        assert_eq!(t.tr("discount"), "0.5");
+        assert_eq!(t.tr("price"), "5");
+        assert_eq!(t.tr("version"), "1.2");
     }
 
-    /// Modifies the current Translations data to load from raw data.
-    /// Note that using this method directly does not support changing locales. If you want that feature, you must use CSV or ODS
-    pub fn data<S>(
-        &mut self,
-        locales: impl Iterator<Item = S>,
-        mapping: impl Iterator<Item = (S, S)>,
-        clear_old_data: bool,
-    ) -> &mut Self
-    where
-        S: ToString,
-    {
-        if clear_old_data {
-            self.available_locales.clear();
-            self.mappings.clear();
-        }
-        self.available_locales = locales.map(|code| code.to_string().trim().into()).collect();
-        for (key, value) in mapping {
-            self.mappings.insert(
-                key.to_string().trim().into(),
-                value.to_string().trim().into(),
-            );
+    #[test]
+    #[cfg(feature = "ods")]
+    fn ods_offsets() {
+        use spreadsheet_ods::{Sheet, WorkBook};
+
+        let mut sheet = Sheet::new("lang");
+        sheet.set_value(0, 0, "Game translations");
+        sheet.set_value(2, 1, "key");
+        sheet.set_value(2, 2, "en");
+        sheet.set_value(2, 3, "es");
+        sheet.set_value(3, 1, "maintained by the localization team");
+        for (row, key, en, es) in [
+            (4, "hello", "hello", "hola"),
+            (5, "green", "green", "verde"),
+        ] {
+            sheet.set_value(row, 1, key);
+            sheet.set_value(row, 2, en);
+            sheet.set_value(row, 3, es);
         }
-        self
-    }
+        let mut book = WorkBook::new_empty();
+        book.push_sheet(sheet);
+        let bytes = spreadsheet_ods::write_ods_buf(&mut book, Vec::new()).unwrap();
 
-    /// A convenience method for calling `use_locale` with the system's default locale.
-    #[cfg(feature = "auto")]
-    pub fn use_system_locale(&mut self) -> &mut Self {
-        self.use_locale(Self::get_system_language().unwrap_or(String::from(
-            self.available_locales.first().cloned().unwrap_or_default(),
-        )))
+        let mut t = Translations::default();
+        t.key_column(1)
+            .ods_header_row(2)
+            .ods_data_row(4)
+            .ods_bytes(bytes, &"en".into(), 0);
+        assert_eq!(t.len(), 2);
+        validate_translation_data(&mut t);
     }
 
-    /// Change the current locale to the new locale if available. Also loads the new mapping data allowing for translations to be loaded immediately.
-    pub fn use_locale<S>(&mut self, locale: S) -> &mut Self
-    where
-        S: ToString + Clone,
-    {
-        // validate this format has a way to load different locales
-        let path = self.path.clone();
-        if path == TableFile::None {
-            eprintln!("Current data format does not allow loading different translation columns.");
-            return self;
-        }
+    #[test]
+    #[cfg(feature = "ods")]
+    fn ods_rich_text_runs() {
+        use spreadsheet_ods::{xmltree::XmlTag, Sheet, Value, WorkBook};
 
-        // validate the requested locale is available
-        let code: LocaleCode = locale.clone().into();
-        if !self.available_locales.contains(&code) {
-            eprintln!("Requested locale is not available: requested {:?}", code);
-            return self;
-        }
-        // self.locale = code;
+        let paragraph = XmlTag::new("text:p")
+            .text("Hello")
+            .tag(XmlTag::new("text:s").attr("text:c", "2"))
+            .tag(XmlTag::new("text:span").text("brave "))
+            .tag(XmlTag::new("text:span").tag(XmlTag::new("text:span").text("new")))
+            .text(" world");
+        let mut sheet = Sheet::new("lang");
+        sheet.set_value(0, 0, "key");
+        sheet.set_value(0, 1, "en");
+        sheet.set_value(1, 0, "hello");
+        sheet.set_value(
+            1,
+            1,
+            Value::TextXml(vec![paragraph, XmlTag::new("text:p").text("again")]),
+        );
+        let mut book = WorkBook::new_empty();
+        book.push_sheet(sheet);
+        let bytes = spreadsheet_ods::write_ods_buf(&mut book, Vec::new()).unwrap();
 
-        // collect the key-value pairs based on the current file format
-        match path {
-            #[cfg(feature = "csv")]
-            TableFile::Csv(str_path) => self.csv_file(Path::new(&str_path), &String::from(code)),
+        let mut t = Translations::default();
+        t.ods_bytes(bytes, &"en".into(), 0);
+        assert_eq!(t.tr("hello"), "Hello  brave new world\nagain");
+    }
 
-            #[cfg(feature = "csv")]
-            TableFile::CsvRaw(raw_data) => self.csv_raw(raw_data, &String::from(code)),
+    #[test]
+    #[cfg(all(feature = "zip", feature = "csv", feature = "ods"))]
+    fn load_from_zip() {
+        let mut t = Translations::default();
+        t.load_from_zip(Path::new(FILE_ZIP), "lang/lang.csv", &"en".into());
+        validate_translation_data(&mut t);
 
-            #[cfg(feature = "ods")]
-            TableFile::Ods(str_path) => self.ods_file(Path::new(&str_path), &String::from(code)),
+        let mut t = Translations::default();
+        t.load_from_zip(Path::new(FILE_ZIP), "lang/lang.ods", &"en".into());
+        validate_translation_data(&mut t);
+    }
 
-            TableFile::None => {
-                unreachable!()
-            }
-        }
+    #[test]
+    #[cfg(all(feature = "zip", feature = "csv", not(feature = "strict")))]
+    fn load_from_zip_missing_entry() {
+        let mut t = Translations::default();
+        t.load_from_zip(Path::new(FILE_ZIP), "missing.csv", &"en".into());
+        assert!(t.is_empty());
     }
 
-    /// Returns an optional string if able to acquire the system's current locale code. Basically just a small wrapper around `bevy_device_lang` for convenience
-    #[cfg(feature = "auto")]
-    pub fn get_system_language() -> Option<String> {
-        bevy_device_lang::get_lang()
+    #[test]
+    #[cfg(all(feature = "strict", feature = "csv"))]
+    #[should_panic(expected = "Failed to load csv file")]
+    fn strict_load_failure() {
+        Translations::default().csv_file(Path::new("assets/missing.csv"), &"en".into());
     }
 
-    /// Consumes and clones the instance to make inserting the resource into a bevy App or World a bit easier when using the builder pattern.
-    pub fn build(&self) -> Self {
-        self.clone() // probably not best practice :/
+    #[test]
+    #[cfg(feature = "xlsx")]
+    fn load_xlsx() {
+        let mut t = Translations::default();
+        t.xlsx_file(Path::new(FILE_XLSX), &"en".into());
+        validate_translation_data(&mut t);
     }
-}
 
-#[cfg(test)]
-mod tests {
+    #[test]
+    #[cfg(feature = "xlsx")]
+    fn xlsx_blank_key_header() {
+        let mut t = Translations::default();
+        t.xlsx_file(Path::new(FILE_XLSX_BLANK_KEY), &"es".into());
+        assert_eq!(t.tr("hello"), "hola");
+        assert_eq!(t.available_locale_strings(), vec!["en", "es"]);
+        t.use_locale("en");
+        assert_eq!(t.tr("green"), "green");
+    }
 
-    use super::*;
-    // need to escape out of the target directory
+    #[test]
+    #[cfg(feature = "csv")]
+    fn builder() {
+        let t = Translations::builder()
+            .source_csv(FILE_CSV)
+            .locale("es")
+            .build();
+        assert_eq!(t.current_locale_string(), "es");
+        assert_eq!(t.tr("hello"), "hola");
 
-    // assets/lang.ods
-    // target/debug/__.rlib
-    const FILE_CSV: &str = "assets/lang.csv";
-    const FILE_ODS: &str = "assets/lang.ods";
+        let t = Translations::builder().source_csv(FILE_CSV).build();
+        assert_eq!(t.tr("hello"), "hello");
+        assert!(Translations::builder().build().is_empty());
+    }
 
     #[test]
-    fn locale_code_lang() {
-        const LOCALE: [&str; 3] = ["en", "es", "fr"];
-        for loc in LOCALE.into_iter() {
-            let code: LocaleCode = loc.into();
-            assert_eq!(code.lang, loc.to_string());
-            assert_eq!(code.region, "".to_string());
-        }
+    #[cfg(feature = "json")]
+    fn load_json() {
+        let mut t = Translations::default();
+        t.json_file(Path::new(DIR_JSON), &"en".into());
+        assert_eq!(t.available_locale_strings(), vec!["en", "es"]);
+        assert_eq!(t.tr("menu.play"), "Play");
+        validate_translation_data(&mut t);
+        assert_eq!(t.tr("menu.play"), "Jugar");
     }
+
     #[test]
-    fn locale_code_lang_region() {
-        const LOCALE: [(&str, &str); 3] = [("en", "AU"), ("es", "CL"), ("fr", "CI")];
-        for (lang, region) in LOCALE.into_iter() {
-            let code: LocaleCode =
-                format!("{}{}{}", lang, LocaleCode::REGION_DELIMITER, region).into();
-            assert_eq!(code.lang, lang.to_string());
-            assert_eq!(code.region, region.to_string());
-        }
+    #[cfg(feature = "toml")]
+    fn load_toml() {
+        let mut t = Translations::default();
+        t.toml_file(Path::new(FILE_TOML), &"en".into());
+        assert_eq!(t.available_locale_strings(), vec!["en", "es"]);
+        assert_eq!(t.tr("menu.play"), "Play");
+        validate_translation_data(&mut t);
+        assert_eq!(t.tr("menu.play"), "Jugar");
     }
 
     #[test]
-    #[cfg(feature = "csv")]
-    fn load_csv_file() {
-        if let Ok(pwd) = std::env::current_dir() {
-            eprintln!("PWD ==> {}", pwd.display());
-        }
+    #[cfg(feature = "yaml")]
+    fn load_yaml() {
         let mut t = Translations::default();
-        t.csv_file(&Path::new(FILE_CSV), &"en".into());
+        t.yaml_file(Path::new(FILE_YAML), &"en".into());
+        assert_eq!(t.available_locale_strings(), vec!["en", "es"]);
+        assert_eq!(t.tr("menu.play"), "Play");
+        assert_eq!(t.tr("days.1"), "Tuesday");
         validate_translation_data(&mut t);
+        assert_eq!(t.tr("menu.play"), "Jugar");
+        assert_eq!(t.tr("days.0"), "lunes");
     }
 
     #[test]
-    #[cfg(feature = "csv")]
-    pub fn load_csv_raw() {
-        const CSV_DATA_RAW: &'static str = r#"key, en, es
-hello, hello, hola,
-green, green, verde"#;
+    #[cfg(feature = "android")]
+    fn load_android() {
+        let mut t = Translations::default();
+        t.android_strings(Path::new(DIR_ANDROID), &"en".into());
+        assert_eq!(t.available_locale_strings(), vec!["en", "es", "pt-BR"]);
+        assert_eq!(t.tr("quote"), "Say \"hi\" & don't\nwait");
+        assert_eq!(t.tr("spaced"), "two  spaces, one line");
+        assert_eq!(t.tr("styled"), "Bold move");
+        validate_translation_data(&mut t);
+        t.use_locale("pt-BR");
+        assert_eq!(t.tr("hello"), "olá");
+    }
 
+    #[test]
+    #[cfg(feature = "apple")]
+    fn load_apple() {
         let mut t = Translations::default();
-        t.csv_raw(CSV_DATA_RAW.into(), &"en".into());
+        t.apple_strings(Path::new(DIR_APPLE), &"en".into());
+        assert_eq!(t.available_locale_strings(), vec!["en", "es"]);
+        assert_eq!(t.tr("quote"), "Say \"hi\"\nnow");
+        assert_eq!(t.tr("bare_key"), "bare value");
+        assert_eq!(t.tr("accent"), "caf\u{e9}");
+        assert_eq!(t.tr("broken"), "broken");
+        validate_translation_data(&mut t);
+    }
+
+    #[test]
+    #[cfg(feature = "gettext")]
+    fn load_po() {
+        let mut t = Translations::default();
+        t.po_file(&Path::new(DIR_PO).join("es.po"));
+        assert_eq!(t.available_locale_strings(), vec!["en", "es"]);
+        assert_eq!(t.tr("quote"), "Dijo \"hola\"\ndos veces");
+        assert_eq!(t.tr("blue"), "blue");
+        assert_eq!(t.tr(""), "");
+        validate_translation_data(&mut t);
+    }
+
+    #[test]
+    #[cfg(feature = "fluent")]
+    fn load_fluent() {
+        let mut t = Translations::default();
+        t.fluent_file(Path::new(DIR_FLUENT), &"en".into());
+        assert_eq!(t.available_locale_strings(), vec!["en", "es"]);
+
+        let mut args = FluentArgs::new();
+        args.set("count", 1);
+        assert_eq!(t.tr_fluent("items", &args), "1 item");
+        args.set("count", 3);
+        assert_eq!(t.tr_fluent("items", &args), "3 items");
+        assert_eq!(t.tr_fluent("invalid", &args), "invalid");
+
         validate_translation_data(&mut t);
+        assert_eq!(t.tr_fluent("items", &args), "3 objetos");
+
+        // data loaded from other sources falls back to `tr`
+        let mut t = Translations::default();
+        t.data(["en"].into_iter(), [("hello", "howdy")].into_iter(), true);
+        assert_eq!(t.tr_fluent("hello", &args), "howdy");
     }
+
     #[test]
     #[cfg(feature = "ods")]
-    fn load_ods() {
+    fn load_ods_sheet() {
         let mut t = Translations::default();
-        t.ods_file(&Path::new(FILE_ODS), &"en".into());
+        t.ods_file_sheet(Path::new(FILE_ODS_SHEETS), &"es".into(), "dialogue");
+        assert_eq!(t.tr("greeting"), "Bienvenido, viajero");
+        t.use_locale("en");
+        assert_eq!(t.tr("greeting"), "Welcome, traveler");
+
+        t.ods_file_sheet(Path::new(FILE_ODS_SHEETS), &"en".into(), "missing");
         validate_translation_data(&mut t);
+
+        t.ods_file_sheet(Path::new(FILE_ODS_SHEETS), &"es".into(), 1);
+        assert_eq!(t.tr("hello"), "buenas");
+
+        t.ods_file_sheet(
+            Path::new(FILE_ODS_SHEETS),
+            &"es".into(),
+            OdsSheet::AllPrefixed,
+        );
+        assert_eq!(t.tr("ui.hello"), "hola");
+        assert_eq!(t.tr("dialogue.hello"), "buenas");
+        assert_eq!(t.available_locale_strings(), vec!["en", "es"]);
     }
 
     #[test]
@@ -439,11 +4670,353 @@ green, green, verde"#;
 
         let mut t = Translations::default();
         t.data(locales.iter(), mappings.into_iter(), true);
+        assert_eq!(t.available_locales(), &["es".into()]);
         assert_eq!(t.tr("hello"), "hola");
         assert_eq!(t.tr("green"), "verde");
         assert_eq!(t.tr("invalid"), "invalid");
     }
 
+    #[test]
+    fn current_locale() {
+        let t = Translations::default();
+        assert_eq!(t.current_locale(), LocaleCode::default());
+        assert_eq!(t.current_locale_string(), "");
+
+        #[cfg(feature = "csv")]
+        {
+            let mut t = Translations::default();
+            t.csv_file(Path::new(FILE_CSV), &"en".into())
+                .use_locale("es");
+            assert_eq!(t.current_locale(), "es".into());
+            assert_eq!(t.current_locale_string(), "es");
+            assert_eq!(t.available_locale_strings(), vec!["en", "es"]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "normalize")]
+    fn normalized_keys() {
+        let mut t = Translations::default();
+        t.data(
+            ["en"].into_iter(),
+            [("cafe\u{301}", "coffee shop")].into_iter(),
+            true,
+        );
+        assert_eq!(t.tr("caf\u{e9}"), "coffee shop");
+        assert_eq!(t.tr("cafe\u{301}"), "coffee shop");
+    }
+
+    #[test]
+    fn case_insensitive_keys() {
+        let mut t = Translations::default();
+        t.data(["en"].into_iter(), [("Hello", "hello")].into_iter(), true);
+        assert_eq!(t.tr("hello"), "hello");
+        assert_eq!(t.tr_opt("hello"), None);
+
+        t.case_insensitive_keys(true);
+        assert_eq!(t.tr_opt("hello"), Some("hello".into()));
+        assert_eq!(t.tr_opt("HELLO"), Some("hello".into()));
+
+        t.data(
+            ["en"].into_iter(),
+            [("Green", "green"), ("GREEN", "GREEN")].into_iter(),
+            true,
+        );
+        assert_eq!(t.tr("green"), "GREEN");
+
+        // keys loaded before enabling it collide the same way, whatever order the map is in
+        let mut t = Translations::default();
+        t.data(
+            ["en"].into_iter(),
+            [("Title", "Title"), ("title", "title"), ("TITLE", "TITLE")].into_iter(),
+            true,
+        );
+        assert!(t.duplicate_keys().is_empty());
+        t.case_insensitive_keys(true);
+        assert_eq!(t.tr("Title"), "title");
+        assert_eq!(
+            t.duplicate_keys(),
+            [
+                ("Title".to_string(), "TITLE".to_string()),
+                ("title".to_string(), "Title".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn data_multi() {
+        let mut t = Translations::default();
+        t.set_fallback_chain(vec!["en".into()]).data_multi(
+            [
+                ("hello", vec![("en", "hello"), ("es", "hola")]),
+                ("green", vec![("en", "green"), ("es", "verde")]),
+                ("blue", vec![("en", "blue")]),
+            ]
+            .into_iter(),
+        );
+        assert_eq!(t.available_locale_strings(), vec!["en", "es"]);
+        assert_eq!(t.current_locale_string(), "en");
+        assert_eq!(t.tr("hello"), "hello");
+
+        t.use_locale("es");
+        assert_eq!(t.tr("hello"), "hola");
+        assert_eq!(t.tr("blue"), "blue");
+        t.use_locale("en");
+        assert_eq!(t.tr("green"), "green");
+
+        let table = HashMap::from([
+            (
+                LocaleCode::from("en"),
+                HashMap::from([("hello".to_string(), "hello".to_string())]),
+            ),
+            (
+                LocaleCode::from("es"),
+                HashMap::from([("hello".to_string(), "hola".to_string())]),
+            ),
+        ]);
+        let mut t = Translations::default();
+        t.memory(table, &"es".into());
+        assert_eq!(t.available_locale_strings(), vec!["en", "es"]);
+        assert_eq!(t.tr("hello"), "hola");
+        t.use_locale("en");
+        assert_eq!(t.tr("hello"), "hello");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn header_case_insensitive() {
+        let mut t = Translations::default();
+        t.csv_raw(
+            "key, EN, Es, es\nhello, hello, hola, buenas".into(),
+            &"es".into(),
+        );
+        assert_eq!(t.tr("hello"), "hola");
+        t.use_locale("en");
+        assert_eq!(t.tr("hello"), "hello");
+    }
+
+    #[test]
+    fn duplicate_keys() {
+        let mut t = Translations::default();
+        t.data(
+            ["en"].into_iter(),
+            [("hello", "first"), ("green", "green"), ("hello", "second")].into_iter(),
+            true,
+        );
+        assert_eq!(t.tr("hello"), "second");
+        assert_eq!(
+            t.duplicate_keys(),
+            &[("hello".to_string(), "first".to_string())]
+        );
+
+        t.data(["en"].into_iter(), [("hello", "third")].into_iter(), false);
+        assert_eq!(t.duplicate_keys().len(), 1);
+        t.data(["en"].into_iter(), [("hello", "hello")].into_iter(), true);
+        assert!(t.duplicate_keys().is_empty());
+    }
+
+    #[test]
+    fn tr_ctx() {
+        let mut t = Translations::default();
+        t.data(
+            ["en"].into_iter(),
+            [
+                ("left", "they left"),
+                ("left@feminine", "she left"),
+                ("left#masculine", "he left"),
+            ]
+            .into_iter(),
+            true,
+        );
+        assert_eq!(t.tr_ctx("left", "feminine"), "she left");
+        assert_eq!(t.tr_ctx("left", "masculine"), "they left");
+        assert_eq!(t.tr_ctx("invalid", "feminine"), "invalid");
+
+        t.context_separator("#");
+        assert_eq!(t.tr_ctx("left", "masculine"), "he left");
+    }
+
+    #[test]
+    fn on_missing() {
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let mut t = Translations::default();
+        t.data(["en"].into_iter(), [("hello", "hello")].into_iter(), true);
+        t.locale = "en".into();
+        let sink = reported.clone();
+        t.on_missing(move |key, locale| {
+            sink.lock().unwrap().push(format!("{}:{}", locale, key));
+        });
+        t.tr("hello");
+        t.tr("invalid");
+        t.tr_or("other", "default");
+        t.tr_opt("quiet");
+        assert_eq!(*reported.lock().unwrap(), vec!["en:invalid", "en:other"]);
+
+        t.clear_on_missing();
+        t.tr("invalid");
+        assert_eq!(reported.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn tr_opt() {
+        let mut t = Translations::default();
+        t.data(["en"].into_iter(), [("same", "same")].into_iter(), true);
+        assert_eq!(t.tr_opt("same"), Some("same".into()));
+        assert_eq!(t.tr_opt("invalid"), None);
+        assert_eq!(t.tr_ref("same"), Some("same"));
+        assert_eq!(t.tr_ref("invalid"), None);
+        let key = String::from("same");
+        assert_eq!(t.tr_ref(&key), Some("same"));
+        assert!(t.contains_key("same"));
+        assert!(!t.contains_key("invalid"));
+    }
+
+    #[test]
+    fn assert_keys() {
+        let mut t = Translations::default();
+        t.data(
+            ["en"].into_iter(),
+            [("hello", "hello"), ("play", "Play")].into_iter(),
+            true,
+        );
+        assert!(t.assert_keys(&["hello", "play"]).is_empty());
+        assert_eq!(
+            t.assert_keys(&["quit", "hello", "options"]),
+            vec!["quit", "options"]
+        );
+    }
+
+    #[test]
+    fn key_for_value() {
+        let mut t = Translations::default();
+        t.data(
+            ["es"].into_iter(),
+            [
+                ("hello", "hola"),
+                ("green", "verde"),
+                ("menu.lime", "verde"),
+                ("color.green", "verde"),
+            ]
+            .into_iter(),
+            true,
+        );
+        assert_eq!(t.key_for_value("hola"), Some(&"hello".to_string()));
+        assert_eq!(t.key_for_value("hello"), None);
+        assert_eq!(t.key_for_value("verde"), Some(&"color.green".to_string()));
+    }
+
+    #[test]
+    fn tr_or() {
+        let mut t = Translations::default();
+        t.data(
+            ["en"].into_iter(),
+            [("hello", "hello"), ("blank", "")].into_iter(),
+            true,
+        );
+        assert_eq!(t.tr_or("hello", "default"), "hello");
+        assert_eq!(t.tr_or("blank", "default"), "");
+        assert_eq!(t.tr_or("invalid", "default"), "default");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn empty_cell_policy() {
+        const CSV_DATA: &str = "key, en, es\nhello, hello, \nblue, blue, azul\nnone, , ";
+        let mut t = Translations::default();
+        t.set_fallback_chain(vec!["en".into()])
+            .csv_raw(CSV_DATA.into(), &"es".into());
+        assert_eq!(t.tr("hello"), "");
+
+        t.empty_cell_policy(EmptyCellPolicy::EchoKey);
+        assert_eq!(t.tr("hello"), "hello");
+        assert_eq!(t.tr_opt("hello"), None);
+        assert_eq!(t.tr("blue"), "azul");
+
+        t.empty_cell_policy(EmptyCellPolicy::FallbackChain);
+        t.use_locale("es");
+        assert_eq!(t.tr_or("hello", "default"), "hello");
+        assert_eq!(t.tr("blue"), "azul");
+        assert_eq!(t.tr_or("none", "default"), "default");
+    }
+
+    #[test]
+    fn tr_args() {
+        let mut t = Translations::default();
+        t.data(
+            ["en"].into_iter(),
+            [
+                ("gold", "You have {count} gold"),
+                ("typo", "Hello {nmae}"),
+                ("escaped", "{{count} is {count}"),
+            ]
+            .into_iter(),
+            true,
+        );
+        let args = HashMap::from([("count".to_string(), "5".to_string())]);
+        assert_eq!(t.tr_args("gold", &args), "You have 5 gold");
+        assert_eq!(t.tr_args("typo", &args), "Hello {nmae}");
+        assert_eq!(t.tr_args("escaped", &args), "{count} is 5");
+        assert_eq!(t.tr_args("invalid", &args), "invalid");
+    }
+
+    #[test]
+    fn tr_fmt() {
+        let mut t = Translations::default();
+        t.data(
+            ["en"].into_iter(),
+            [
+                ("indexed", "{1} beats {0}"),
+                ("sequential", "{} and {}"),
+                ("out_of_range", "{0} {3}"),
+                ("named", "{name}"),
+            ]
+            .into_iter(),
+            true,
+        );
+        assert_eq!(t.tr_fmt("indexed", &["rock", "paper"]), "paper beats rock");
+        assert_eq!(t.tr_fmt("sequential", &["a", "b"]), "a and b");
+        assert_eq!(t.tr_fmt("out_of_range", &["a", "b"]), "a {3}");
+        assert_eq!(t.tr_fmt("named", &["a"]), "{name}");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn fallback_chain() {
+        const CSV_DATA_RAW: &str = r#"key, en, fr, fr-CA
+hello, hello, bonjour, allo
+green, green, vert,
+blue, blue, ,"#;
+
+        let mut t = Translations::default();
+        t.csv_raw(CSV_DATA_RAW.into(), &"fr-CA".into())
+            .set_fallback_chain(vec!["fr".into(), "en".into()]);
+        assert_eq!(t.tr("hello"), "allo");
+        assert_eq!(t.tr("invalid"), "invalid");
+
+        // empty cells are still values, so only cells missing from short rows fall back
+        let mut t = Translations::default();
+        t.set_fallback_chain(vec!["fr".into(), "en".into()])
+            .csv_raw(
+                "key, en, fr, fr-CA\nhello, hello, bonjour\nblue, blue".into(),
+                &"fr-CA".into(),
+            );
+        assert_eq!(t.tr("hello"), "bonjour");
+        assert_eq!(t.tr("blue"), "blue");
+        assert_eq!(t.tr("invalid"), "invalid");
+    }
+
+    #[cfg(any(
+        feature = "csv",
+        feature = "ods",
+        feature = "xlsx",
+        feature = "json",
+        feature = "toml",
+        feature = "yaml",
+        feature = "gettext",
+        feature = "fluent",
+        feature = "android",
+        feature = "apple"
+    ))]
     fn validate_translation_data(trans: &mut Translations) {
         // eprintln!("Raw Loaded: {:#?}\n", trans);
 
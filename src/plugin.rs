@@ -0,0 +1,396 @@
+#[cfg(any(feature = "csv", feature = "ods"))]
+use std::path::PathBuf;
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::prelude::*;
+
+use crate::{LocaleCode, Translations};
+
+#[cfg(feature = "bevy_asset")]
+use crate::{apply_translation_asset, TranslationAsset, TranslationHandle, TranslationLoader};
+
+/// The source data the `TranslationPlugin` will load when building the App.
+#[derive(Clone, Debug)]
+enum PluginSource {
+    #[cfg(feature = "csv")]
+    Csv(PathBuf),
+    #[cfg(feature = "ods")]
+    Ods(PathBuf),
+    #[cfg(feature = "bevy_asset")]
+    Asset(String),
+    Prebuilt(Box<Translations>),
+}
+
+/// A bevy Plugin that inserts the `Translations` resource and registers the `SetLocale` event along with the system that applies it.
+///
+/// ```no_run
+/// # use bevy_app::App;
+/// # use bevy_translation_table::TranslationPlugin;
+/// # #[cfg(feature = "csv")]
+/// App::new().add_plugins(TranslationPlugin::from_csv("assets/lang.csv", "en"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct TranslationPlugin {
+    source: PluginSource,
+    locale: String,
+    fallback_chain: Vec<LocaleCode>,
+}
+
+impl TranslationPlugin {
+    /// Creates a plugin that loads the given CSV file using the provided default locale.
+    #[cfg(feature = "csv")]
+    pub fn from_csv(path: impl Into<PathBuf>, locale: impl ToString) -> Self {
+        Self {
+            source: PluginSource::Csv(path.into()),
+            locale: locale.to_string(),
+            fallback_chain: Vec::new(),
+        }
+    }
+
+    /// Creates a plugin that loads the given ODS file using the provided default locale.
+    #[cfg(feature = "ods")]
+    pub fn from_ods(path: impl Into<PathBuf>, locale: impl ToString) -> Self {
+        Self {
+            source: PluginSource::Ods(path.into()),
+            locale: locale.to_string(),
+            fallback_chain: Vec::new(),
+        }
+    }
+
+    /// Creates a plugin that loads a `.csv` or `.ods` table through the `AssetServer`, relative to the asset folder. The `AssetPlugin` must be added first.
+    /// The `Translations` resource starts out empty and is filled in once the asset has loaded, and again whenever the asset is reloaded.
+    #[cfg(feature = "bevy_asset")]
+    pub fn from_asset(path: impl ToString, locale: impl ToString) -> Self {
+        Self {
+            source: PluginSource::Asset(path.to_string()),
+            locale: locale.to_string(),
+            fallback_chain: Vec::new(),
+        }
+    }
+
+    /// Creates a plugin that inserts an already configured `Translations` resource as is.
+    pub fn from_translations(translations: Translations) -> Self {
+        Self {
+            locale: translations.current_locale_string(),
+            fallback_chain: translations.fallback_chain.clone(),
+            source: PluginSource::Prebuilt(Box::new(translations)),
+        }
+    }
+
+    /// Changes the default locale that is loaded when the App is built.
+    pub fn with_locale(mut self, locale: impl ToString) -> Self {
+        self.locale = locale.to_string();
+        self
+    }
+
+    /// Sets the ordered locales to fall back to when the current locale is missing a key.
+    pub fn with_fallback_chain(mut self, locales: Vec<LocaleCode>) -> Self {
+        self.fallback_chain = locales;
+        self
+    }
+
+    fn load(&self) -> Translations {
+        let mut translations = Translations::default();
+        translations.set_fallback_chain(self.fallback_chain.clone());
+        match &self.source {
+            #[cfg(feature = "csv")]
+            PluginSource::Csv(path) => translations.csv_file(path, &self.locale).build(),
+            #[cfg(feature = "ods")]
+            PluginSource::Ods(path) => translations.ods_file(path, &self.locale).build(),
+            #[cfg(feature = "bevy_asset")]
+            PluginSource::Asset(_) => Translations {
+                locale: translations.locale_code(&self.locale),
+                ..translations
+            },
+            PluginSource::Prebuilt(prebuilt) => prebuilt
+                .clone()
+                .set_fallback_chain(self.fallback_chain.clone())
+                .build(),
+        }
+    }
+}
+
+impl Plugin for TranslationPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.load())
+            .add_event::<SetLocale>()
+            .add_event::<LocaleChanged>()
+            .add_systems(
+                PreUpdate,
+                (apply_locale_changes, send_locale_changed)
+                    .chain()
+                    .in_set(TranslationSet::Apply),
+            );
+
+        #[cfg(feature = "bevy_text")]
+        app.add_systems(
+            bevy_app::PostUpdate,
+            crate::update_localized_text.before(bevy_text::update_text2d_layout),
+        );
+
+        #[cfg(feature = "reflect")]
+        app.register_type::<Translations>()
+            .register_type::<LocaleCode>()
+            .register_type::<crate::TableFile>();
+        #[cfg(all(feature = "reflect", feature = "bevy_text"))]
+        app.register_type::<crate::LocalizedText>();
+
+        #[cfg(feature = "bevy_asset")]
+        if let PluginSource::Asset(path) = &self.source {
+            use bevy_asset::{AssetApp, AssetServer};
+
+            app.init_asset::<TranslationAsset>()
+                .init_asset_loader::<TranslationLoader>();
+            let handle = app.world.resource::<AssetServer>().load(path.clone());
+            app.insert_resource(TranslationHandle(handle)).add_systems(
+                PreUpdate,
+                apply_translation_asset
+                    .before(apply_locale_changes)
+                    .in_set(TranslationSet::Apply),
+            );
+        }
+    }
+}
+
+/// The system sets of the `TranslationPlugin`, for ordering your own systems around locale changes.
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TranslationSet {
+    /// Loads the translation asset, applies `SetLocale` requests, and sends `LocaleChanged`, all in `PreUpdate`.
+    /// Systems in `PreUpdate` ordered `.after(TranslationSet::Apply)` see the new locale in the same frame instead of showing the old language for one frame.
+    Apply,
+}
+
+/// An event requesting the `Translations` resource switch to a new locale. Requests are applied once per frame by the `TranslationPlugin`.
+#[derive(Event, Clone, Debug, PartialEq)]
+pub struct SetLocale(pub LocaleCode);
+
+impl SetLocale {
+    pub fn new(locale: impl Into<LocaleCode>) -> Self {
+        Self(locale.into())
+    }
+}
+
+/// An event sent by the `TranslationPlugin` after the locale of the `Translations` resource has changed, whether through `SetLocale` or by calling `use_locale` directly.
+#[derive(Event, Clone, Debug, PartialEq)]
+pub struct LocaleChanged {
+    pub from: LocaleCode,
+    pub to: LocaleCode,
+}
+
+/// Applies pending `SetLocale` events to the `Translations` resource. When several requests arrive in the same frame only the latest one is applied.
+/// The resource is only flagged as changed when the locale actually switches, so requests for the current or an unavailable locale do not trigger `Changed<Translations>`.
+pub fn apply_locale_changes(
+    mut events: EventReader<SetLocale>,
+    mut translations: ResMut<Translations>,
+) {
+    if let Some(SetLocale(locale)) = events.read().last() {
+        let locale = translations.locale_string(locale);
+        let previous = translations.locale.clone();
+        translations.bypass_change_detection().use_locale(locale);
+        if translations.locale != previous {
+            translations.set_changed();
+        }
+    }
+}
+
+/// Sends a `LocaleChanged` event when the `Translations` resource has recorded a locale change. Several changes within a frame are reported as a single event.
+pub fn send_locale_changed(
+    mut translations: ResMut<Translations>,
+    mut events: EventWriter<LocaleChanged>,
+) {
+    if translations.pending_change.is_none() {
+        return;
+    }
+    // the locale switch itself already flagged the resource as changed
+    let translations = translations.bypass_change_detection();
+    if let Some(from) = translations.take_pending_change() {
+        if from != translations.locale {
+            events.send(LocaleChanged {
+                from,
+                to: translations.locale.clone(),
+            });
+        }
+    }
+}
+
+#[cfg(all(test, feature = "csv"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_locale_event() {
+        let mut app = App::new();
+        app.add_plugins(TranslationPlugin::from_csv("assets/lang.csv", "en"));
+        app.update();
+        assert_eq!(app.world.resource::<Translations>().tr("hello"), "hello");
+
+        app.world.send_event(SetLocale::new("es"));
+        app.update();
+        assert_eq!(app.world.resource::<Translations>().tr("hello"), "hola");
+
+        let changes = app.world.resource::<Events<LocaleChanged>>();
+        let changes = changes
+            .get_reader()
+            .read(changes)
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            vec![LocaleChanged {
+                from: "en".into(),
+                to: "es".into()
+            }]
+        );
+    }
+
+    #[test]
+    fn ordered_after_apply() {
+        #[derive(Resource, Default)]
+        struct Label(String);
+
+        fn update_label(translations: Res<Translations>, mut label: ResMut<Label>) {
+            label.0 = translations.tr("hello");
+        }
+
+        let mut app = App::new();
+        app.add_plugins(TranslationPlugin::from_csv("assets/lang.csv", "en"))
+            .init_resource::<Label>()
+            .add_systems(PreUpdate, update_label.after(TranslationSet::Apply));
+        app.update();
+        assert_eq!(app.world.resource::<Label>().0, "hello");
+
+        app.world.send_event(SetLocale::new("es"));
+        app.update();
+        assert_eq!(app.world.resource::<Label>().0, "hola");
+    }
+
+    #[test]
+    fn change_detection() {
+        #[derive(Resource, Default)]
+        struct Changes(usize);
+
+        fn count_changes(translations: Res<Translations>, mut changes: ResMut<Changes>) {
+            if translations.is_changed() {
+                changes.0 += 1;
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins(TranslationPlugin::from_csv("assets/lang.csv", "en"))
+            .init_resource::<Changes>()
+            .add_systems(bevy_app::Update, count_changes);
+        app.update();
+        assert_eq!(app.world.resource::<Changes>().0, 1);
+
+        for (locale, expected) in [("es", 2), ("es", 2), ("invalid", 2), ("en", 3)] {
+            app.world.send_event(SetLocale::new(locale));
+            app.update();
+            assert_eq!(app.world.resource::<Changes>().0, expected, "{}", locale);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "bevy_asset")]
+    fn load_from_asset() {
+        let mut app = App::new();
+        app.add_plugins((
+            bevy_core::TaskPoolPlugin::default(),
+            bevy_asset::AssetPlugin::default(),
+            TranslationPlugin::from_asset("lang.csv", "es"),
+        ));
+        for _ in 0..500 {
+            app.update();
+            if !app.world.resource::<Translations>().is_empty() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert_eq!(app.world.resource::<Translations>().tr("hello"), "hola");
+
+        app.world.send_event(SetLocale::new("en"));
+        app.update();
+        assert_eq!(app.world.resource::<Translations>().tr("hello"), "hello");
+    }
+
+    #[test]
+    #[cfg(feature = "bevy_asset")]
+    fn asset_keeps_configuration() {
+        let mut app = App::new();
+        app.add_plugins((
+            bevy_core::TaskPoolPlugin::default(),
+            bevy_asset::AssetPlugin::default(),
+            TranslationPlugin::from_asset("lang.csv", "es"),
+        ));
+        app.world
+            .resource_mut::<Translations>()
+            .case_insensitive_keys(true)
+            .apply_overrides([("green".to_string(), "verde claro".to_string())].into());
+        // the override alone already fills the mappings, so wait for the locales of the table
+        for _ in 0..500 {
+            app.update();
+            if app.world.resource::<Translations>().has_locale("es") {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        let translations = app.world.resource::<Translations>();
+        assert_eq!(translations.tr("HELLO"), "hola");
+        assert_eq!(translations.tr("green"), "verde claro");
+    }
+
+    #[test]
+    #[cfg(feature = "bevy_asset")]
+    fn asset_missing_locale() {
+        let mut app = App::new();
+        app.add_plugins((
+            bevy_core::TaskPoolPlugin::default(),
+            bevy_asset::AssetPlugin::default(),
+            TranslationPlugin::from_asset("lang.csv", "de"),
+        ));
+        for _ in 0..500 {
+            app.update();
+            if app.world.resource::<Translations>().has_locale("en") {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        let translations = app.world.resource::<Translations>();
+        assert_eq!(translations.current_locale_string(), "en");
+        assert_eq!(translations.tr("hello"), "hello");
+        let changes = app
+            .world
+            .resource_mut::<Events<LocaleChanged>>()
+            .drain()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            vec![LocaleChanged {
+                from: "de".into(),
+                to: "en".into()
+            }]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "reflect")]
+    fn reflect_resource() {
+        use bevy_reflect::ReflectRef;
+
+        let mut app = App::new();
+        app.add_plugins(TranslationPlugin::from_csv("assets/lang.csv", "es"));
+        let registry = app.world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let registration = registry
+            .get(std::any::TypeId::of::<Translations>())
+            .unwrap();
+        let reflect_resource = registration.data::<ReflectResource>().unwrap();
+        let resource = reflect_resource.reflect(&app.world).unwrap();
+        let ReflectRef::Struct(translations) = resource.reflect_ref() else {
+            panic!("Translations should reflect as a struct");
+        };
+        let locale = translations.field("locale").unwrap();
+        assert!(locale
+            .reflect_partial_eq(&crate::LocaleCode::from("es"))
+            .unwrap_or_default());
+    }
+}
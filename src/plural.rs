@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use intl_pluralrules::{PluralRuleType, PluralRules};
+use unic_langid::LanguageIdentifier;
+
+pub use intl_pluralrules::PluralCategory;
+
+use crate::Translations;
+
+type PluralFn = dyn Fn(i64) -> PluralCategory + Send + Sync;
+
+/// A plural rule registered with `set_plural_rule`, shared between clones of the resource.
+#[derive(Clone)]
+pub(crate) struct PluralRule(Arc<PluralFn>);
+
+impl std::fmt::Debug for PluralRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PluralRule")
+    }
+}
+
+impl PartialEq for PluralRule {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Translations {
+    /// Translates the plural variant of a key that matches `count` under the CLDR plural rules of the current language.
+    /// Variants are stored as sub-keys named after their category, such as `apple.one` and `apple.other`. When the selected category is missing `key.other` is used, and after that the plain `key` as in `tr`.
+    pub fn tr_plural(&self, key: impl AsRef<str>, count: i64) -> String {
+        let key = key.as_ref();
+        self.tr_opt(format!(
+            "{}.{}",
+            key,
+            Self::plural_suffix(self.plural_category(count))
+        ))
+        .or_else(|| self.tr_opt(format!("{}.other", key)))
+        .unwrap_or_else(|| self.tr(key))
+    }
+
+    /// Translates the plural variant of a key like `tr_plural` and then replaces `{name}` placeholders in it like `tr_args`.
+    /// `{count}` is filled in with `count` unless `args` provides its own `count` entry, such as a preformatted number.
+    pub fn tr_plural_args(
+        &self,
+        key: impl AsRef<str>,
+        count: i64,
+        args: &HashMap<String, String>,
+    ) -> String {
+        Self::interpolate(&self.tr_plural(key, count), |name| match args.get(name) {
+            Some(value) => Some(value.clone()),
+            None if name == "count" => Some(count.to_string()),
+            None => None,
+        })
+    }
+
+    /// Selects the CLDR plural category of `count` for the current language, defaulting to `OTHER` for unknown languages.
+    /// A rule registered with `set_plural_rule` for the language is used instead of the CLDR rules.
+    pub fn plural_category(&self, count: i64) -> PluralCategory {
+        if let Some(rule) = self.plural_rules.get(&self.locale.lang.to_lowercase()) {
+            return (rule.0)(count);
+        }
+        self.locale
+            .lang
+            .parse::<LanguageIdentifier>()
+            .ok()
+            .and_then(|lang| PluralRules::create(lang, PluralRuleType::CARDINAL).ok())
+            .and_then(|rules| rules.select(count).ok())
+            .unwrap_or(PluralCategory::OTHER)
+    }
+
+    /// Registers the plural rule of a language that CLDR does not cover, such as a conlang, or replaces the CLDR rule of a language.
+    /// The rule selects the category of a count, and is used by `tr_plural` and `plural_category` whenever the current locale is of that language.
+    /// Rules are shared between clones of the resource and are not serialized.
+    pub fn set_plural_rule(
+        &mut self,
+        lang: &str,
+        rule: impl Fn(i64) -> PluralCategory + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.plural_rules
+            .insert(lang.trim().to_lowercase(), PluralRule(Arc::new(rule)));
+        self
+    }
+
+    pub(crate) fn plural_suffix(category: PluralCategory) -> &'static str {
+        match category {
+            PluralCategory::ZERO => "zero",
+            PluralCategory::ONE => "one",
+            PluralCategory::TWO => "two",
+            PluralCategory::FEW => "few",
+            PluralCategory::MANY => "many",
+            PluralCategory::OTHER => "other",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plural_data(locale: &str) -> Translations {
+        let mut t = Translations::default();
+        t.data(
+            [locale].into_iter(),
+            [
+                ("apple.one", "{count} apple"),
+                ("apple.few", "{count} apples (few)"),
+                ("apple.many", "{count} apples (many)"),
+                ("apple.other", "{count} apples"),
+                ("pear", "pears"),
+                ("basket.one", "{name} has {count} basket"),
+                ("basket.other", "{name} has {count} baskets"),
+            ]
+            .into_iter(),
+            true,
+        );
+        t.locale = locale.into();
+        t
+    }
+
+    #[test]
+    fn tr_plural_english() {
+        let t = plural_data("en");
+        assert_eq!(t.tr_plural("apple", 1), "{count} apple");
+        assert_eq!(t.tr_plural("apple", 2), "{count} apples");
+        assert_eq!(t.tr_plural("apple", 0), "{count} apples");
+        assert_eq!(t.tr_plural("pear", 1), "pears");
+        assert_eq!(t.tr_plural("invalid", 1), "invalid");
+    }
+
+    #[test]
+    fn tr_plural_polish() {
+        let t = plural_data("pl-PL");
+        assert_eq!(t.tr_plural("apple", 1), "{count} apple");
+        assert_eq!(t.tr_plural("apple", 3), "{count} apples (few)");
+        assert_eq!(t.tr_plural("apple", 5), "{count} apples (many)");
+    }
+
+    #[test]
+    fn tr_plural_args_count() {
+        let t = plural_data("en");
+        let args = HashMap::from([("name".to_string(), "Ana".to_string())]);
+        assert_eq!(t.tr_plural_args("basket", 1, &args), "Ana has 1 basket");
+        assert_eq!(t.tr_plural_args("basket", 3, &args), "Ana has 3 baskets");
+        assert_eq!(t.tr_plural_args("apple", 2, &HashMap::new()), "2 apples");
+
+        let args = HashMap::from([("count".to_string(), "1,000".to_string())]);
+        assert_eq!(t.tr_plural_args("apple", 1000, &args), "1,000 apples");
+    }
+
+    #[test]
+    fn custom_plural_rule() {
+        let mut t = plural_data("tlh");
+        assert_eq!(t.tr_plural("apple", 1), "{count} apples");
+        t.set_plural_rule("tlh", |count| match count {
+            1 => PluralCategory::ONE,
+            2..=4 => PluralCategory::FEW,
+            _ => PluralCategory::OTHER,
+        });
+        assert_eq!(t.tr_plural("apple", 1), "{count} apple");
+        assert_eq!(t.tr_plural("apple", 3), "{count} apples (few)");
+        assert_eq!(t.tr_plural("apple", 7), "{count} apples");
+
+        // overrides replace the CLDR rules of known languages too
+        let mut t = plural_data("en");
+        t.set_plural_rule("EN", |_| PluralCategory::MANY);
+        assert_eq!(t.tr_plural("apple", 1), "{count} apples (many)");
+    }
+}
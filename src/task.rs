@@ -0,0 +1,95 @@
+#[cfg(any(feature = "csv", feature = "ods"))]
+use std::path::PathBuf;
+
+use bevy_ecs::prelude::*;
+use bevy_tasks::{block_on, poll_once, Task};
+#[cfg(any(feature = "csv", feature = "ods"))]
+use bevy_tasks::{AsyncComputeTaskPool, TaskPool};
+
+use crate::Translations;
+
+/// A resource holding translations that are still being parsed in the background. Once parsing completes, `insert_loaded_translations` replaces it with the `Translations` resource.
+/// Checking for this resource is a simple way to keep a loading screen up.
+#[derive(Resource)]
+pub struct TranslationTask(pub Task<Translations>);
+
+impl Translations {
+    /// Parses a CSV file on the `AsyncComputeTaskPool`, keeping the configuration of this instance such as its fallback chain and CSV options.
+    /// The returned task can be awaited, or stored in a `TranslationTask` resource to be inserted by `insert_loaded_translations`.
+    #[cfg(feature = "csv")]
+    pub fn load_csv_async(self, path: impl Into<PathBuf>, locale: impl ToString) -> Task<Self> {
+        let path = path.into();
+        let locale = locale.to_string();
+        Self::spawn(self, move |mut translations| {
+            translations.csv_file(&path, &locale);
+            translations
+        })
+    }
+
+    /// Parses an ODS file on the `AsyncComputeTaskPool`, keeping the configuration of this instance such as its fallback chain.
+    /// The returned task can be awaited, or stored in a `TranslationTask` resource to be inserted by `insert_loaded_translations`.
+    #[cfg(feature = "ods")]
+    pub fn load_ods_async(self, path: impl Into<PathBuf>, locale: impl ToString) -> Task<Self> {
+        let path = path.into();
+        let locale = locale.to_string();
+        Self::spawn(self, move |mut translations| {
+            translations.ods_file(&path, &locale);
+            translations
+        })
+    }
+
+    #[cfg(any(feature = "csv", feature = "ods"))]
+    fn spawn(translations: Self, load: impl FnOnce(Self) -> Self + Send + 'static) -> Task<Self> {
+        AsyncComputeTaskPool::get_or_init(TaskPool::default)
+            .spawn(async move { load(translations) })
+    }
+}
+
+/// Inserts the `Translations` resource once the background parsing of the `TranslationTask` resource completes, then removes the task.
+pub fn insert_loaded_translations(mut commands: Commands, task: Option<ResMut<TranslationTask>>) {
+    let Some(mut task) = task else {
+        return;
+    };
+    if let Some(translations) = block_on(poll_once(&mut task.0)) {
+        commands.insert_resource(translations);
+        commands.remove_resource::<TranslationTask>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "ods")]
+    fn await_ods_task() {
+        let task = Translations::default().load_ods_async("assets/lang.ods", "es");
+        let translations = block_on(task);
+        assert_eq!(translations.tr("hello"), "hola");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn insert_when_finished() {
+        let mut world = World::new();
+        let mut translations = Translations::default();
+        translations.set_fallback_chain(vec!["en".into()]);
+        world.insert_resource(TranslationTask(
+            translations.build().load_csv_async("assets/lang.csv", "es"),
+        ));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(insert_loaded_translations);
+        for _ in 0..500 {
+            schedule.run(&mut world);
+            if world.contains_resource::<Translations>() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert!(!world.contains_resource::<TranslationTask>());
+        let translations = world.resource::<Translations>();
+        assert_eq!(translations.tr("green"), "verde");
+        assert_eq!(translations.fallback_chain, vec!["en".into()]);
+    }
+}
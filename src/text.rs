@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+use bevy_text::{Text, TextSection, TextStyle};
+
+use crate::Translations;
+
+/// The text styles used by `tr_sections`. Text outside of any tag uses `base`, and text inside a `[tag]...[/tag]` pair uses the style registered for that tag.
+#[derive(Clone, Debug, Default)]
+pub struct StyleMap {
+    pub base: TextStyle,
+    pub tags: HashMap<String, TextStyle>,
+}
+
+impl StyleMap {
+    pub fn new(base: TextStyle) -> Self {
+        Self {
+            base,
+            tags: HashMap::new(),
+        }
+    }
+
+    /// Registers the style for text wrapped in `[tag]...[/tag]`.
+    pub fn with_tag(mut self, tag: impl ToString, style: TextStyle) -> Self {
+        self.tags.insert(tag.to_string(), style);
+        self
+    }
+
+    fn style(&self, tag: Option<&&str>) -> TextStyle {
+        tag.and_then(|tag| self.tags.get(*tag))
+            .unwrap_or(&self.base)
+            .clone()
+    }
+}
+
+impl Translations {
+    /// Translates the key and splits the value into styled `TextSection`s using a small bbcode-like markup, such as `Press [b]Start[/b] to play`.
+    /// Tags can be nested, in which case the innermost tag decides the style. Unknown tags and closing tags that do not match the innermost open tag are kept as literal text.
+    pub fn tr_sections(&self, key: impl AsRef<str>, styles: &StyleMap) -> Vec<TextSection> {
+        parse_sections(&self.tr(key), styles)
+    }
+}
+
+/// A component holding the translation key of an entity's `Text`. The `TranslationPlugin` keeps the text translated whenever the key or the `Translations` resource changes.
+/// The translated value replaces the text of the first section, keeping its style, and any other sections are removed.
+#[derive(Component, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "reflect", reflect(Component))]
+pub struct LocalizedText(pub String);
+
+impl LocalizedText {
+    pub fn new(key: impl ToString) -> Self {
+        Self(key.to_string())
+    }
+}
+
+/// Writes the translation of each `LocalizedText` key into the `Text` of the same entity, for new or changed keys and for every key after the `Translations` resource changes.
+/// Texts that already hold the translation are not touched, so their change detection is only triggered by real changes.
+pub fn update_localized_text(
+    translations: Res<Translations>,
+    mut texts: Query<(Ref<LocalizedText>, &mut Text)>,
+) {
+    let refresh_all = translations.is_changed();
+    for (key, mut text) in texts.iter_mut() {
+        if !refresh_all && !key.is_changed() {
+            continue;
+        }
+        let value = translations.tr(&key.0);
+        let unchanged = text.sections.len() == 1 && text.sections[0].value == value;
+        if unchanged {
+            continue;
+        }
+        match text.sections.first_mut() {
+            Some(section) => section.value = value,
+            None => text
+                .sections
+                .push(TextSection::new(value, TextStyle::default())),
+        }
+        text.sections.truncate(1);
+    }
+}
+
+fn parse_sections(text: &str, styles: &StyleMap) -> Vec<TextSection> {
+    let mut sections = Vec::new();
+    let mut open: Vec<&str> = Vec::new();
+    let mut value = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('[') {
+        let Some(end) = rest[start..].find(']').map(|offset| start + offset) else {
+            break;
+        };
+        value.push_str(&rest[..start]);
+        let tag = &rest[start + 1..end];
+        let is_markup = match tag.strip_prefix('/') {
+            Some(name) => open.last() == Some(&name),
+            None => styles.tags.contains_key(tag),
+        };
+        if is_markup {
+            if !value.is_empty() {
+                sections.push(TextSection::new(
+                    std::mem::take(&mut value),
+                    styles.style(open.last()),
+                ));
+            }
+            if tag.starts_with('/') {
+                open.pop();
+            } else {
+                open.push(tag);
+            }
+        } else {
+            value.push_str(&rest[start..=end]);
+        }
+        rest = &rest[end + 1..];
+    }
+    value.push_str(rest);
+    if !value.is_empty() {
+        sections.push(TextSection::new(value, styles.style(open.last())));
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sized(font_size: f32) -> TextStyle {
+        TextStyle {
+            font_size,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn markup_sections() {
+        let mut t = Translations::default();
+        t.data(
+            ["en"].into_iter(),
+            [
+                ("start", "Press [b]Start[/b] to [i]play [b]now[/b][/i]"),
+                ("literal", "[u]odd[/u] [b]tags[/i]"),
+            ]
+            .into_iter(),
+            true,
+        );
+        let styles = StyleMap::new(sized(10.0))
+            .with_tag("b", sized(20.0))
+            .with_tag("i", sized(30.0));
+
+        let sections = t
+            .tr_sections("start", &styles)
+            .into_iter()
+            .map(|section| (section.value, section.style.font_size))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sections,
+            vec![
+                ("Press ".to_string(), 10.0),
+                ("Start".to_string(), 20.0),
+                (" to ".to_string(), 10.0),
+                ("play ".to_string(), 30.0),
+                ("now".to_string(), 20.0),
+            ]
+        );
+
+        let sections = t
+            .tr_sections("literal", &styles)
+            .into_iter()
+            .map(|section| (section.value, section.style.font_size))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sections,
+            vec![
+                ("[u]odd[/u] ".to_string(), 10.0),
+                ("tags[/i]".to_string(), 20.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn localized_text() {
+        let mut world = World::new();
+        let mut t = Translations::default();
+        t.data_multi([("play", vec![("en", "Play"), ("es", "Jugar")])].into_iter());
+        world.insert_resource(t);
+        let entity = world
+            .spawn((
+                LocalizedText::new("play"),
+                Text::from_section("", sized(12.0)),
+            ))
+            .id();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_localized_text);
+
+        let text = |world: &World| world.get::<Text>(entity).unwrap().sections.clone();
+        schedule.run(&mut world);
+        assert_eq!(text(&world)[0].value, "Play");
+        assert_eq!(text(&world)[0].style.font_size, 12.0);
+
+        world.resource_mut::<Translations>().use_locale("es");
+        schedule.run(&mut world);
+        assert_eq!(text(&world)[0].value, "Jugar");
+
+        world.get_mut::<LocalizedText>(entity).unwrap().0 = "quit".into();
+        schedule.run(&mut world);
+        assert_eq!(text(&world)[0].value, "quit");
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        mpsc::{channel, Receiver},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use bevy_ecs::prelude::*;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::Translations;
+
+/// A Resource that watches the source file of a `Translations` resource for changes on disk.
+/// Pair it with the `reload_on_change` system to reload the current locale whenever the file is modified.
+#[derive(Resource)]
+pub struct TranslationWatcher {
+    _watcher: RecommendedWatcher,
+    events: Mutex<Receiver<notify::Result<notify::Event>>>,
+    target: PathBuf,
+    is_dir: bool,
+    debounce: Duration,
+    pending: Option<Instant>,
+}
+
+impl TranslationWatcher {
+    /// The default delay waited after the latest change before reloading, which coalesces editors writing a file several times in a row.
+    pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(250);
+
+    /// Starts watching the source of the given translations. Fails if the source is not backed by a file or directory.
+    pub fn new(translations: &Translations) -> notify::Result<Self> {
+        let Some(target) = translations.source_path().map(Path::to_path_buf) else {
+            return Err(notify::Error::generic(
+                "translation source is not backed by a file",
+            ));
+        };
+        let is_dir = target.is_dir();
+        // watching the parent directory keeps working when editors save by replacing the file
+        let watched = if is_dir {
+            target.as_path()
+        } else {
+            target
+                .parent()
+                .filter(|p| !p.as_os_str().is_empty())
+                .unwrap_or(Path::new("."))
+        };
+        let (sender, receiver) = channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        watcher.watch(watched, RecursiveMode::NonRecursive)?;
+        Ok(Self {
+            _watcher: watcher,
+            events: Mutex::new(receiver),
+            target,
+            is_dir,
+            debounce: Self::DEFAULT_DEBOUNCE,
+            pending: None,
+        })
+    }
+
+    /// Changes how long to wait after the latest change before reloading.
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Drains the queued file system events and returns true once a change has settled for the debounce duration.
+    fn poll(&mut self) -> bool {
+        if let Ok(events) = self.events.lock() {
+            for event in events.try_iter().flatten() {
+                if event.kind.is_access() || !event.paths.iter().any(|p| self.affects(p)) {
+                    continue;
+                }
+                self.pending = Some(Instant::now());
+            }
+        }
+        match self.pending {
+            Some(changed) if changed.elapsed() >= self.debounce => {
+                self.pending = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn affects(&self, path: &Path) -> bool {
+        if self.is_dir {
+            true
+        } else {
+            path.file_name() == self.target.file_name()
+        }
+    }
+}
+
+/// Reloads the currently active locale of the `Translations` resource once the watched source file has changed.
+pub fn reload_on_change(
+    mut watcher: ResMut<TranslationWatcher>,
+    mut translations: ResMut<Translations>,
+) {
+    if watcher.poll() {
+        let locale = translations.current_locale_string();
+        translations.use_locale(locale);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::system::RunSystemOnce;
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn reload_modified_csv() {
+        let dir = std::env::temp_dir().join(format!("translation-watch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("lang.csv");
+        std::fs::write(&file, "key, en\nhello, hello").unwrap();
+
+        let mut world = World::new();
+        let translations = Translations::default()
+            .csv_file(&file, &"en".into())
+            .build();
+        let watcher = TranslationWatcher::new(&translations)
+            .unwrap()
+            .with_debounce(Duration::ZERO);
+        world.insert_resource(translations);
+        world.insert_resource(watcher);
+
+        std::fs::write(&file, "key, en\nhello, howdy").unwrap();
+        let start = Instant::now();
+        while world.resource::<Translations>().tr("hello") != "howdy"
+            && start.elapsed() < Duration::from_secs(5)
+        {
+            std::thread::sleep(Duration::from_millis(20));
+            world.run_system_once(reload_on_change);
+        }
+        assert_eq!(world.resource::<Translations>().tr("hello"), "howdy");
+        std::fs::remove_dir_all(dir).ok();
+    }
+}